@@ -1,4 +1,4 @@
-use hindu_calendar::ephemeris::Ephemeris;
+use hindu_calendar::ephemeris::{Ephemeris, Ayanamsha};
 use hindu_calendar::model::*;
 use hindu_calendar::core::{panchang, solar};
 
@@ -12,6 +12,8 @@ fn print_usage(prog: &str) {
          \x20              (if omitted, shows lunisolar panchang)\n\
          \x20 -l LAT,LON   Location (default: New Delhi 28.6139,77.2090)\n\
          \x20 -u OFFSET    UTC offset in hours (default: 5.5)\n\
+         \x20 -k           Also show Rahu Kalam/Yamaganda/Gulika Kalam (day mode only)\n\
+         \x20 -a SYSTEM    Sidereal ayanamsa: lahiri (default), raman, kp, fagan-bradley\n\
          \x20 -h           Show this help",
         prog
     );
@@ -26,6 +28,16 @@ fn days_in_greg_month(year: i32, month: i32) -> i32 {
     }
 }
 
+fn parse_ayanamsha(s: &str) -> Option<Ayanamsha> {
+    match s {
+        "lahiri" => Some(Ayanamsha::Lahiri),
+        "raman" => Some(Ayanamsha::Raman),
+        "kp" => Some(Ayanamsha::Kp),
+        "fagan-bradley" => Some(Ayanamsha::FaganBradley),
+        _ => None,
+    }
+}
+
 fn parse_solar_type(s: &str) -> Option<SolarCalendarType> {
     match s {
         "tamil" => Some(SolarCalendarType::Tamil),
@@ -122,6 +134,8 @@ fn main() {
     let mut loc = Location::NEW_DELHI;
     let mut solar_mode = false;
     let mut solar_type = SolarCalendarType::Tamil;
+    let mut show_kalam = false;
+    let mut ayanamsha = Ayanamsha::default();
 
     let mut i = 1;
     while i < args.len() {
@@ -172,6 +186,20 @@ fn main() {
                 i += 1;
                 loc.utc_offset = args[i].parse().unwrap_or(loc.utc_offset);
             }
+            "-k" => {
+                show_kalam = true;
+            }
+            "-a" if i + 1 < args.len() => {
+                i += 1;
+                match parse_ayanamsha(&args[i]) {
+                    Some(a) => ayanamsha = a,
+                    None => {
+                        eprintln!("Error: unknown ayanamsa system '{}'", args[i]);
+                        eprintln!("Valid systems: lahiri, raman, kp, fagan-bradley");
+                        std::process::exit(1);
+                    }
+                }
+            }
             "-h" => {
                 print_usage(&args[0]);
                 return;
@@ -191,6 +219,7 @@ fn main() {
     }
 
     let mut eph = Ephemeris::new();
+    eph.set_ayanamsha(ayanamsha);
 
     if solar_mode {
         if day > 0 {
@@ -199,20 +228,12 @@ fn main() {
             print_solar_month(&mut eph, year, month, &loc, solar_type);
         }
     } else if day > 0 {
-        let jd = eph.gregorian_to_jd(year, month, day);
-        let jd_sunrise = eph.sunrise_jd(jd, &loc);
-        let ti = hindu_calendar::core::tithi::tithi_at_sunrise(&mut eph, year, month, day, &loc);
-        let hd = panchang::gregorian_to_hindu(&mut eph, year, month, day, &loc);
-
-        let pd = PanchangDay {
-            greg_year: year,
-            greg_month: month,
-            greg_day: day,
-            jd_sunrise,
-            hindu_date: hd,
-            tithi: ti,
-        };
-        panchang::print_day_panchang(&eph, &pd, loc.utc_offset);
+        let pd = panchang::panchang_for_day(&mut eph, year, month, day, &loc);
+        if show_kalam {
+            panchang::print_day_panchang_with_muhurta(&mut eph, &pd, &loc);
+        } else {
+            panchang::print_day_panchang(&eph, &pd, loc.utc_offset);
+        }
     } else {
         println!("Hindu Calendar — {:04}-{:02} ({:.4}°N, {:.4}°E, UTC{:+.1})\n",
             year, month, loc.latitude, loc.longitude, loc.utc_offset);
@@ -0,0 +1,245 @@
+use crate::ephemeris::Ephemeris;
+use crate::ephemeris::moon;
+use super::tithi;
+
+const SYNODIC_MONTH: f64 = 29.530589;
+
+/// Angular radius of the Moon's disc as seen from Earth, in degrees.
+fn moon_angular_radius_deg(distance_km: f64) -> f64 {
+    (1737.4 / distance_km).atan().to_degrees()
+}
+
+/// Penumbral and umbral shadow cone radii at the Moon's distance, in
+/// degrees — a fixed approximation of the usual mean values, not a full
+/// Besselian-element reduction.
+const PENUMBRAL_RADIUS_DEG: f64 = 1.2;
+const UMBRAL_RADIUS_DEG: f64 = 0.7;
+
+/// Angular radius of the Sun's disc as seen from Earth, in degrees (mean).
+const SUN_ANGULAR_RADIUS_DEG: f64 = 0.2667;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EclipseKind {
+    Lunar,
+    Solar,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchDirection {
+    Forward,
+    Backward,
+}
+
+/// An eclipse event, its contact times as JDs (UT). `total_*` are `None`
+/// when the eclipse never reaches totality (e.g. a penumbral-only or
+/// partial lunar eclipse, or an annular/partial solar eclipse).
+#[derive(Debug, Clone, Copy)]
+pub struct EclipseEvent {
+    pub kind: EclipseKind,
+    pub max_jd: f64,
+    pub max_separation_deg: f64,
+    pub partial_begin: f64,
+    pub partial_end: f64,
+    pub total_begin: Option<f64>,
+    pub total_end: Option<f64>,
+}
+
+fn find_syzygy(eph: &mut Ephemeris, jd_guess: f64, target_phase: f64) -> f64 {
+    // Centre the +/-2 day bracket on a mean-motion estimate of the first
+    // syzygy at or after `jd_guess`.
+    let ahead = (target_phase - tithi::lunar_phase(eph, jd_guess)).rem_euclid(360.0);
+    let center = jd_guess + ahead / 360.0 * SYNODIC_MONTH;
+    let mut lo = center - 2.0;
+    let mut hi = center + 2.0;
+    for _ in 0..50 {
+        let mid = (lo + hi) / 2.0;
+        let phase = tithi::lunar_phase(eph, mid);
+        let mut diff = phase - target_phase;
+        if diff > 180.0 { diff -= 360.0; }
+        if diff < -180.0 { diff += 360.0; }
+        if diff >= 0.0 { hi = mid; } else { lo = mid; }
+    }
+    (lo + hi) / 2.0
+}
+
+/// Separation between Moon and shadow/Sun axis at `jd_ut`: the Moon's
+/// ecliptic latitude at full/new moon stands in for the true miss distance,
+/// since longitude coincides with the shadow axis (lunar) or the Sun
+/// (solar) by construction of the syzygy.
+fn separation_deg(jd_ut: f64) -> f64 {
+    moon::lunar_latitude_mean(jd_ut).abs()
+}
+
+/// Bracket the time (near `t_max`, within `window` days) where `separation_deg`
+/// first rises to `radius`, searching backward from `t_max` for a begin time
+/// and forward for an end time.
+fn bracket_contact(t_max: f64, radius: f64, window: f64) -> Option<(f64, f64)> {
+    if separation_deg(t_max) >= radius {
+        return None;
+    }
+
+    let step = window / 200.0;
+    let mut begin = None;
+    let mut t = t_max;
+    while t > t_max - window {
+        if separation_deg(t) >= radius {
+            begin = Some(bisect_contact(t, t + step, radius));
+            break;
+        }
+        t -= step;
+    }
+
+    let mut end = None;
+    let mut t = t_max;
+    while t < t_max + window {
+        if separation_deg(t) >= radius {
+            end = Some(bisect_contact(t - step, t, radius));
+            break;
+        }
+        t += step;
+    }
+
+    match (begin, end) {
+        (Some(b), Some(e)) => Some((b, e)),
+        _ => None,
+    }
+}
+
+fn bisect_contact(mut lo: f64, mut hi: f64, radius: f64) -> f64 {
+    for _ in 0..40 {
+        let mid = (lo + hi) / 2.0;
+        if separation_deg(mid) >= radius {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    (lo + hi) / 2.0
+}
+
+fn eclipse_at(eph: &mut Ephemeris, jd_max: f64, kind: EclipseKind) -> EclipseEvent {
+    let distance_km = eph.lunar_longitude_and_distance(jd_max).1;
+    let moon_r = moon_angular_radius_deg(distance_km);
+
+    // Totality radius: the Moon's disc wholly inside the umbra (lunar), or
+    // wholly covering the Sun (solar; negative, hence never reached, when
+    // the Moon's disc is the smaller one).
+    let (penumbral_r, umbral_r) = match kind {
+        EclipseKind::Lunar => (PENUMBRAL_RADIUS_DEG + moon_r, UMBRAL_RADIUS_DEG - moon_r),
+        EclipseKind::Solar => (moon_r + SUN_ANGULAR_RADIUS_DEG, moon_r - SUN_ANGULAR_RADIUS_DEG),
+    };
+
+    let max_separation = separation_deg(jd_max);
+    let (partial_begin, partial_end) =
+        bracket_contact(jd_max, penumbral_r, 0.3).unwrap_or((jd_max, jd_max));
+    let total = bracket_contact(jd_max, umbral_r, 0.3);
+
+    EclipseEvent {
+        kind,
+        max_jd: jd_max,
+        max_separation_deg: max_separation,
+        partial_begin,
+        partial_end,
+        total_begin: total.map(|(b, _)| b),
+        total_end: total.map(|(_, e)| e),
+    }
+}
+
+/// Node-proximity screen radius for `kind`: a syzygy whose Moon latitude
+/// exceeds this is too far from the node to be an eclipse candidate at all,
+/// regardless of whether it turns out partial/total.
+fn screening_limit(kind: EclipseKind) -> f64 {
+    match kind {
+        EclipseKind::Lunar => PENUMBRAL_RADIUS_DEG + 0.6,
+        EclipseKind::Solar => moon_angular_radius_deg(385000.529) + SUN_ANGULAR_RADIUS_DEG + 0.6,
+    }
+}
+
+/// Check a single known syzygy (e.g. a month's Amavasya/Purnima JD, already
+/// produced by `masa::new_moon_before`/`new_moon_after`) for an eclipse,
+/// without scanning — `None` if the Moon's latitude there is too far from
+/// the node to be a candidate.
+pub fn eclipse_candidate(eph: &mut Ephemeris, jd_syzygy: f64, kind: EclipseKind) -> Option<EclipseEvent> {
+    if separation_deg(jd_syzygy).abs() > screening_limit(kind) {
+        return None;
+    }
+
+    Some(eclipse_at(eph, jd_syzygy, kind))
+}
+
+/// All solar and lunar eclipses with maximum phase between `jd_start` and
+/// `jd_end`, in chronological order. Walks `find_eclipse` forward one
+/// synodic month at a time per kind, so a multi-year range costs a handful
+/// of syzygy searches rather than a day-by-day scan.
+///
+/// `find_eclipse` falls back to a synthetic zero-width "non-eclipse" event
+/// when its scan doesn't land near a node (see its doc comment); apply the
+/// same `screening_limit` guard `eclipse_candidate` uses before accepting an
+/// event here, so that fallback never gets reported as a real eclipse.
+pub fn eclipses_in_range(eph: &mut Ephemeris, jd_start: f64, jd_end: f64) -> Vec<EclipseEvent> {
+    let mut events = Vec::new();
+    for kind in [EclipseKind::Solar, EclipseKind::Lunar] {
+        let mut jd = jd_start - SYNODIC_MONTH;
+        loop {
+            let event = find_eclipse(eph, jd, kind, SearchDirection::Forward);
+            if event.max_jd > jd_end {
+                break;
+            }
+            if event.max_jd >= jd_start && event.max_separation_deg.abs() <= screening_limit(kind) {
+                events.push(event);
+            }
+            // find_syzygy's +/-2 day bracket can land slightly before `jd`
+            // itself; always step forward from whichever instant is later so
+            // a syzygy found "behind" the search anchor can't stall the scan.
+            jd = (event.max_jd + 1.0).max(jd + 1.0);
+        }
+    }
+    events.sort_by(|a, b| a.max_jd.partial_cmp(&b.max_jd).unwrap());
+    events
+}
+
+/// Find the next/previous eclipse of `kind` from `jd_ut`, scanning one
+/// synodic month at a time until a syzygy lands close enough to a node.
+/// If none of the 30 syzygies scanned qualifies, returns the next syzygy
+/// after them, unscreened, with zero-width contacts and no totality.
+pub fn find_eclipse(
+    eph: &mut Ephemeris,
+    jd_ut: f64,
+    kind: EclipseKind,
+    direction: SearchDirection,
+) -> EclipseEvent {
+    let target_phase = match kind {
+        EclipseKind::Lunar => 180.0,
+        EclipseKind::Solar => 0.0,
+    };
+    let step = match direction {
+        SearchDirection::Forward => SYNODIC_MONTH,
+        SearchDirection::Backward => -SYNODIC_MONTH,
+    };
+
+    let limit = screening_limit(kind);
+
+    // find_syzygy looks forward from its guess, so a backward search starts
+    // one month back to land on the syzygy before `jd_ut`.
+    let mut jd_guess = if step < 0.0 { jd_ut + step } else { jd_ut };
+    for _ in 0..30 {
+        let jd_syzygy = find_syzygy(eph, jd_guess, target_phase);
+        if separation_deg(jd_syzygy).abs() <= limit {
+            return eclipse_at(eph, jd_syzygy, kind);
+        }
+        jd_guess += step;
+    }
+
+    // No eclipse found in the scanned range; report the next syzygy after it
+    // as a (non-)eclipse with zero-width contacts rather than panicking.
+    let jd_syzygy = find_syzygy(eph, jd_guess, target_phase);
+    EclipseEvent {
+        kind,
+        max_jd: jd_syzygy,
+        max_separation_deg: separation_deg(jd_syzygy),
+        partial_begin: jd_syzygy,
+        partial_end: jd_syzygy,
+        total_begin: None,
+        total_end: None,
+    }
+}
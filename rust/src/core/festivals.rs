@@ -0,0 +1,104 @@
+use crate::ephemeris::Ephemeris;
+use crate::model::*;
+use super::{angam, panchang, solar};
+use super::angam::AngamKind;
+
+/// One dated occurrence of a festival: the Gregorian day that hosts it and
+/// the lunisolar date that justified the match.
+#[derive(Debug, Clone, Copy)]
+pub struct FestivalOccurrence {
+    pub name: &'static str,
+    pub greg_year: i32,
+    pub greg_month: i32,
+    pub greg_day: i32,
+    pub hindu_date: HinduDate,
+}
+
+/// A rule mapping a named festival to a position in the lunisolar or
+/// lunisolar/solar calendars.
+enum FestivalRule {
+    /// A single (masa, paksha, tithi) triple, e.g. Rama Navami.
+    Tithi { masa: MasaName, paksha: Paksha, tithi: i32, name: &'static str },
+    /// An inclusive run of tithis within one (masa, paksha), e.g. Navaratri.
+    TithiRange { masa: MasaName, paksha: Paksha, tithi_start: i32, tithi_end: i32, name: &'static str },
+    /// A nakshatra falling within a named solar month, e.g. Onam (Thiruvonam
+    /// nakshatra in the Malayalam solar month Chingam).
+    SolarNakshatra { cal_type: SolarCalendarType, solar_month: i32, nakshatra: i32, name: &'static str },
+}
+
+// Nakshatra index is 1-based, matching `NAKSHATRA_NAMES`/`angam::angam_at`.
+const RULES: &[FestivalRule] = &[
+    FestivalRule::Tithi { masa: MasaName::Chaitra, paksha: Paksha::Shukla, tithi: 9, name: "Rama Navami" },
+    FestivalRule::Tithi { masa: MasaName::Bhadrapada, paksha: Paksha::Krishna, tithi: 8, name: "Krishna Janmashtami" },
+    FestivalRule::Tithi { masa: MasaName::Magha, paksha: Paksha::Krishna, tithi: 14, name: "Maha Shivaratri" },
+    FestivalRule::Tithi { masa: MasaName::Kartika, paksha: Paksha::Krishna, tithi: 15, name: "Diwali" },
+    FestivalRule::Tithi { masa: MasaName::Ashvina, paksha: Paksha::Shukla, tithi: 10, name: "Vijayadashami" },
+    FestivalRule::TithiRange { masa: MasaName::Ashvina, paksha: Paksha::Shukla, tithi_start: 1, tithi_end: 9, name: "Navaratri" },
+    FestivalRule::SolarNakshatra { cal_type: SolarCalendarType::Malayalam, solar_month: 1, nakshatra: 22, name: "Onam" },
+];
+
+fn days_in_month(year: i32, month: i32) -> i32 {
+    const MDAYS: [i32; 13] = [0, 31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    if month == 2 && ((year % 4 == 0 && year % 100 != 0) || year % 400 == 0) {
+        return 29;
+    }
+    MDAYS[month as usize]
+}
+
+/// Resolve all festival occurrences that fall within a Gregorian year for
+/// an observer at `loc`. A (masa, paksha, tithi) rule only matches the nija
+/// (regular) month, never the adhika (intercalary) one, so an adhika masa
+/// never shifts a fixed festival early.
+pub fn festivals_for_year(eph: &mut Ephemeris, year: i32, loc: &Location) -> Vec<FestivalOccurrence> {
+    let mut out = Vec::new();
+
+    for month in 1..=12 {
+        for day in 1..=days_in_month(year, month) {
+            let hd = panchang::gregorian_to_hindu(eph, year, month, day, loc);
+
+            for rule in RULES {
+                let name = match rule {
+                    FestivalRule::Tithi { masa, paksha, tithi, name } => {
+                        if !hd.is_adhika_masa && hd.masa == *masa && hd.paksha == *paksha && hd.tithi == *tithi {
+                            Some(*name)
+                        } else {
+                            None
+                        }
+                    }
+                    FestivalRule::TithiRange { masa, paksha, tithi_start, tithi_end, name } => {
+                        if !hd.is_adhika_masa && hd.masa == *masa && hd.paksha == *paksha
+                            && hd.tithi >= *tithi_start && hd.tithi <= *tithi_end
+                        {
+                            Some(*name)
+                        } else {
+                            None
+                        }
+                    }
+                    FestivalRule::SolarNakshatra { cal_type, solar_month, nakshatra, name } => {
+                        let sd = solar::gregorian_to_solar(eph, year, month, day, loc, *cal_type);
+                        if sd.month == *solar_month {
+                            let jd = eph.gregorian_to_jd(year, month, day);
+                            let jd_sunrise = eph.sunrise_jd(jd, loc);
+                            let (index, _) = angam::angam_at(eph, jd_sunrise, AngamKind::Nakshatra);
+                            if index == *nakshatra { Some(*name) } else { None }
+                        } else {
+                            None
+                        }
+                    }
+                };
+
+                if let Some(name) = name {
+                    out.push(FestivalOccurrence {
+                        name,
+                        greg_year: year,
+                        greg_month: month,
+                        greg_day: day,
+                        hindu_date: hd,
+                    });
+                }
+            }
+        }
+    }
+
+    out
+}
@@ -0,0 +1,140 @@
+use crate::ephemeris::Ephemeris;
+use crate::ephemeris::julian_day::revjul;
+use crate::model::*;
+use super::festivals::FestivalOccurrence;
+
+/// Whether panchang VEVENTs cover the whole Gregorian day or the actual
+/// tithi window (`TithiInfo::jd_start`/`jd_end`, which are true JD-UT
+/// instants, so no `Location` offset is needed to render them).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IcalMode {
+    AllDay,
+    Timed,
+}
+
+fn escape_text(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace(',', "\\,")
+        .replace(';', "\\;")
+        .replace('\n', "\\n")
+}
+
+fn format_date(year: i32, month: i32, day: i32) -> String {
+    format!("{:04}{:02}{:02}", year, month, day)
+}
+
+/// Render a JD-UT instant as an iCalendar UTC `DATE-TIME` (`YYYYMMDDTHHMMSSZ`).
+fn format_utc_datetime(jd_ut: f64) -> String {
+    let (hour_frac, year, month, day) = revjul(jd_ut);
+    let total_secs = (hour_frac * 3600.0).round() as i64;
+    let total_secs = total_secs.rem_euclid(86400);
+    let hour = total_secs / 3600;
+    let minute = (total_secs / 60) % 60;
+    let second = total_secs % 60;
+    format!("{}T{:02}{:02}{:02}Z", format_date(year, month, day), hour, minute, second)
+}
+
+fn push_vevent(
+    out: &mut String,
+    uid: &str,
+    dtstart: String,
+    dtend: String,
+    summary: &str,
+    description: &str,
+    alarm_description: Option<&str>,
+) {
+    out.push_str("BEGIN:VEVENT\r\n");
+    out.push_str(&format!("UID:{}\r\n", uid));
+    out.push_str(&format!("DTSTART{}\r\n", dtstart));
+    out.push_str(&format!("DTEND{}\r\n", dtend));
+    out.push_str(&format!("SUMMARY:{}\r\n", escape_text(summary)));
+    out.push_str(&format!("DESCRIPTION:{}\r\n", escape_text(description)));
+    if let Some(desc) = alarm_description {
+        out.push_str("BEGIN:VALARM\r\n");
+        out.push_str("ACTION:DISPLAY\r\n");
+        out.push_str(&format!("DESCRIPTION:{}\r\n", escape_text(desc)));
+        out.push_str("TRIGGER:-P1D\r\n");
+        out.push_str("END:VALARM\r\n");
+    }
+    out.push_str("END:VEVENT\r\n");
+}
+
+/// Serialize a month/range of `PanchangDay`s and `FestivalOccurrence`s into
+/// a standards-compliant (RFC 5545) `.ics` VCALENDAR string. Festival
+/// VEVENTs carry a `VALARM` reminder when `with_alarms` is set.
+pub fn export_calendar(
+    eph: &Ephemeris,
+    days: &[PanchangDay],
+    festivals: &[FestivalOccurrence],
+    mode: IcalMode,
+    with_alarms: bool,
+) -> String {
+    let mut out = String::new();
+    out.push_str("BEGIN:VCALENDAR\r\n");
+    out.push_str("VERSION:2.0\r\n");
+    out.push_str("PRODID:-//hindu-calendar//panchang//EN\r\n");
+    out.push_str("CALSCALE:GREGORIAN\r\n");
+
+    for pd in days {
+        let masa_str = MASA_NAMES[pd.hindu_date.masa.number() as usize];
+        let paksha_str = match pd.tithi.paksha {
+            Paksha::Shukla => "Shukla",
+            Paksha::Krishna => "Krishna",
+        };
+        let tithi_name = TITHI_NAMES[pd.tithi.paksha_tithi as usize];
+        let nakshatra_name = NAKSHATRA_NAMES[pd.nakshatra.index as usize];
+        let yoga_name = YOGA_NAMES[pd.yoga.index as usize];
+
+        let summary = format!("{} {} — {} {}", paksha_str, tithi_name, masa_str, nakshatra_name);
+        let description = format!(
+            "Masa: {}\nPaksha: {}\nTithi: {}\nNakshatra: {}\nYoga: {}\nSaka: {}",
+            masa_str, paksha_str, tithi_name, nakshatra_name, yoga_name, pd.hindu_date.year_saka
+        );
+        let uid = format!(
+            "{}-{}-{}-{}-{}@hindu-calendar",
+            pd.hindu_date.year_saka, pd.hindu_date.masa.number(), paksha_str,
+            pd.tithi.paksha_tithi, pd.greg_day
+        );
+
+        let (dtstart, dtend) = match mode {
+            IcalMode::AllDay => {
+                let jd = eph.gregorian_to_jd(pd.greg_year, pd.greg_month, pd.greg_day);
+                let (ny, nm, nd) = eph.jd_to_gregorian(jd + 1.0);
+                (
+                    format!(";VALUE=DATE:{}", format_date(pd.greg_year, pd.greg_month, pd.greg_day)),
+                    format!(";VALUE=DATE:{}", format_date(ny, nm, nd)),
+                )
+            }
+            IcalMode::Timed => (
+                format!(":{}", format_utc_datetime(pd.tithi.jd_start)),
+                format!(":{}", format_utc_datetime(pd.tithi.jd_end)),
+            ),
+        };
+
+        push_vevent(&mut out, &uid, dtstart, dtend, &summary, &description, None);
+    }
+
+    for fo in festivals {
+        let masa_str = MASA_NAMES[fo.hindu_date.masa.number() as usize];
+        let summary = fo.name.to_string();
+        let description = format!(
+            "{} — {} {}, tithi {}, Saka {}",
+            fo.name, masa_str, fo.hindu_date.paksha, fo.hindu_date.tithi, fo.hindu_date.year_saka
+        );
+        let uid = format!(
+            "{}-{:04}{:02}{:02}@hindu-calendar",
+            fo.name.replace(' ', "-").to_lowercase(), fo.greg_year, fo.greg_month, fo.greg_day
+        );
+
+        let jd = eph.gregorian_to_jd(fo.greg_year, fo.greg_month, fo.greg_day);
+        let (ny, nm, nd) = eph.jd_to_gregorian(jd + 1.0);
+        let dtstart = format!(";VALUE=DATE:{}", format_date(fo.greg_year, fo.greg_month, fo.greg_day));
+        let dtend = format!(";VALUE=DATE:{}", format_date(ny, nm, nd));
+
+        let alarm = if with_alarms { Some(format!("{} tomorrow", fo.name)) } else { None };
+        push_vevent(&mut out, &uid, dtstart, dtend, &summary, &description, alarm.as_deref());
+    }
+
+    out.push_str("END:VCALENDAR\r\n");
+    out
+}
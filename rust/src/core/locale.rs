@@ -0,0 +1,155 @@
+use crate::model::{MasaName, Paksha};
+use super::angam;
+
+/// Script/language a name table is rendered in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    /// Romanized English, the existing `MASA_NAMES`/`TITHI_NAMES`/etc. style.
+    English,
+    /// Devanagari.
+    Devanagari,
+    /// IAST romanization with diacritics.
+    Iast,
+}
+
+/// A panchanga quantity that can render its name in a given `Locale`.
+pub trait Localized {
+    fn name(&self, locale: Locale) -> &'static str;
+}
+
+const MASA_NAMES_DEVANAGARI: [&str; 13] = [
+    "",
+    "चैत्र", "वैशाख", "ज्येष्ठ", "आषाढ़", "श्रावण", "भाद्रपद",
+    "आश्विन", "कार्तिक", "मार्गशीर्ष", "पौष", "माघ", "फाल्गुन",
+];
+
+const MASA_NAMES_IAST: [&str; 13] = [
+    "",
+    "Caitra", "Vaiśākha", "Jyeṣṭha", "Āṣāḍha", "Śrāvaṇa", "Bhādrapada",
+    "Āśvina", "Kārtika", "Mārgaśīrṣa", "Pauṣa", "Māgha", "Phālguna",
+];
+
+impl Localized for MasaName {
+    fn name(&self, locale: Locale) -> &'static str {
+        let n = self.number() as usize;
+        match locale {
+            Locale::English => self.display_name(),
+            Locale::Devanagari => MASA_NAMES_DEVANAGARI[n],
+            Locale::Iast => MASA_NAMES_IAST[n],
+        }
+    }
+}
+
+impl Localized for Paksha {
+    fn name(&self, locale: Locale) -> &'static str {
+        match (self, locale) {
+            (Paksha::Shukla, Locale::English) => "Shukla",
+            (Paksha::Shukla, Locale::Devanagari) => "शुक्ल",
+            (Paksha::Shukla, Locale::Iast) => "Śukla",
+            (Paksha::Krishna, Locale::English) => "Krishna",
+            (Paksha::Krishna, Locale::Devanagari) => "कृष्ण",
+            (Paksha::Krishna, Locale::Iast) => "Kṛṣṇa",
+        }
+    }
+}
+
+const TITHI_NAMES_DEVANAGARI: [&str; 16] = [
+    "",
+    "प्रतिपदा", "द्वितीया", "तृतीया", "चतुर्थी", "पञ्चमी", "षष्ठी",
+    "सप्तमी", "अष्टमी", "नवमी", "दशमी", "एकादशी", "द्वादशी",
+    "त्रयोदशी", "चतुर्दशी", "पूर्णिमा",
+];
+
+const TITHI_NAMES_IAST: [&str; 16] = [
+    "",
+    "Pratipadā", "Dvitīyā", "Tṛtīyā", "Caturthī", "Pañcamī", "Ṣaṣṭhī",
+    "Saptamī", "Aṣṭamī", "Navamī", "Daśamī", "Ekādaśī", "Dvādaśī",
+    "Trayodaśī", "Caturdaśī", "Pūrṇimā",
+];
+
+/// Localized name of paksha-tithi `n` (1-15; use 15 for both Purnima and
+/// Amavasya, matching `crate::model::TITHI_NAMES`'s convention).
+pub fn tithi_name(n: i32, locale: Locale) -> &'static str {
+    match locale {
+        Locale::English => crate::model::TITHI_NAMES[n as usize],
+        Locale::Devanagari => TITHI_NAMES_DEVANAGARI[n as usize],
+        Locale::Iast => TITHI_NAMES_IAST[n as usize],
+    }
+}
+
+const NAKSHATRA_NAMES_DEVANAGARI: [&str; 28] = [
+    "",
+    "अश्विनी", "भरणी", "कृत्तिका", "रोहिणी", "मृगशिरा", "आर्द्रा",
+    "पुनर्वसु", "पुष्य", "आश्लेषा", "मघा", "पूर्वाफाल्गुनी", "उत्तराफाल्गुनी",
+    "हस्त", "चित्रा", "स्वाती", "विशाखा", "अनुराधा", "ज्येष्ठा",
+    "मूल", "पूर्वाषाढ़ा", "उत्तराषाढ़ा", "श्रवण", "धनिष्ठा", "शतभिषा",
+    "पूर्वभाद्रपदा", "उत्तरभाद्रपदा", "रेवती",
+];
+
+const NAKSHATRA_NAMES_IAST: [&str; 28] = [
+    "",
+    "Aśvinī", "Bharaṇī", "Kṛttikā", "Rohiṇī", "Mṛgaśirā", "Ārdrā",
+    "Punarvasu", "Puṣya", "Āśleṣā", "Maghā", "Pūrvā Phalgunī", "Uttarā Phalgunī",
+    "Hasta", "Citrā", "Svātī", "Viśākhā", "Anurādhā", "Jyeṣṭhā",
+    "Mūla", "Pūrvāṣāḍhā", "Uttarāṣāḍhā", "Śravaṇa", "Dhaniṣṭhā", "Śatabhiṣā",
+    "Pūrva Bhādrapadā", "Uttara Bhādrapadā", "Revatī",
+];
+
+/// Localized name of nakshatra `index` (1-27).
+pub fn nakshatra_name(index: i32, locale: Locale) -> &'static str {
+    match locale {
+        Locale::English => crate::model::NAKSHATRA_NAMES[index as usize],
+        Locale::Devanagari => NAKSHATRA_NAMES_DEVANAGARI[index as usize],
+        Locale::Iast => NAKSHATRA_NAMES_IAST[index as usize],
+    }
+}
+
+const YOGA_NAMES_DEVANAGARI: [&str; 28] = [
+    "",
+    "विष्कम्भ", "प्रीति", "आयुष्मान्", "सौभाग्य", "शोभन", "अतिगण्ड",
+    "सुकर्मा", "धृति", "शूल", "गण्ड", "वृद्धि", "ध्रुव",
+    "व्याघात", "हर्षण", "वज्र", "सिद्धि", "व्यतीपात", "वरीयान्",
+    "परिघ", "शिव", "सिद्ध", "साध्य", "शुभ", "शुक्ल",
+    "ब्रह्म", "इन्द्र", "वैधृति",
+];
+
+const YOGA_NAMES_IAST: [&str; 28] = [
+    "",
+    "Viṣkambha", "Prīti", "Āyuṣmān", "Saubhāgya", "Śobhana", "Atigaṇḍa",
+    "Sukarmā", "Dhṛti", "Śūla", "Gaṇḍa", "Vṛddhi", "Dhruva",
+    "Vyāghāta", "Harṣaṇa", "Vajra", "Siddhi", "Vyatīpāta", "Varīyān",
+    "Parigha", "Śiva", "Siddha", "Sādhya", "Śubha", "Śukla",
+    "Brahma", "Indra", "Vaidhṛti",
+];
+
+/// Localized name of yoga `index` (1-27).
+pub fn yoga_name(index: i32, locale: Locale) -> &'static str {
+    match locale {
+        Locale::English => crate::model::YOGA_NAMES[index as usize],
+        Locale::Devanagari => YOGA_NAMES_DEVANAGARI[index as usize],
+        Locale::Iast => YOGA_NAMES_IAST[index as usize],
+    }
+}
+
+const KARANA_NAMES_DEVANAGARI: [&str; 12] = [
+    "",
+    "किंस्तुघ्न", "बव", "बालव", "कौलव", "तैतिल", "गर",
+    "वणिज", "विष्टि", "शकुनि", "चतुष्पद", "नाग",
+];
+
+const KARANA_NAMES_IAST: [&str; 12] = [
+    "",
+    "Kiṃstughna", "Bava", "Bālava", "Kaulava", "Taitila", "Gara",
+    "Vaṇija", "Viṣṭi", "Śakuni", "Catuṣpada", "Nāga",
+];
+
+/// Localized name of the karana active for half-tithi number `karana_num`
+/// (1-60, cycling monthly — see `angam::karana_index`).
+pub fn karana_name(karana_num: i32, locale: Locale) -> &'static str {
+    let index = angam::karana_index(karana_num);
+    match locale {
+        Locale::English => crate::model::KARANA_NAMES[index],
+        Locale::Devanagari => KARANA_NAMES_DEVANAGARI[index],
+        Locale::Iast => KARANA_NAMES_IAST[index],
+    }
+}
@@ -1,31 +1,7 @@
 use crate::ephemeris::Ephemeris;
 use crate::model::*;
 use super::tithi;
-
-/// Inverse Lagrange interpolation
-fn inverse_lagrange(x: &[f64], y: &[f64], n: usize, ya: f64) -> f64 {
-    let mut total = 0.0;
-    for i in 0..n {
-        let mut numer = 1.0;
-        let mut denom = 1.0;
-        for j in 0..n {
-            if j != i {
-                numer *= ya - y[j];
-                denom *= y[i] - y[j];
-            }
-        }
-        total += numer * x[i] / denom;
-    }
-    total
-}
-
-fn unwrap_angles(angles: &mut [f64]) {
-    for i in 1..angles.len() {
-        if angles[i] < angles[i - 1] {
-            angles[i] += 360.0;
-        }
-    }
-}
+use super::angam::{inverse_lagrange, unwrap_angles};
 
 pub fn new_moon_before(eph: &mut Ephemeris, jd_ut: f64, tithi_hint: i32) -> f64 {
     let start = jd_ut - tithi_hint as f64;
@@ -53,6 +29,42 @@ pub fn new_moon_after(eph: &mut Ephemeris, jd_ut: f64, tithi_hint: i32) -> f64 {
     start + y0
 }
 
+/// Mirror of `new_moon_before` targeting the preceding full moon (Purnima,
+/// phase 180°), used for Purnimanta month boundaries.
+fn full_moon_before(eph: &mut Ephemeris, jd_ut: f64, tithi_hint: i32) -> f64 {
+    // At tithi 15 the full moon is still ahead; the preceding one is a month back.
+    let days_since = match (tithi_hint - 15).rem_euclid(30) {
+        0 => 30.0,
+        n => n as f64,
+    };
+    let start = jd_ut - days_since;
+    let mut x = [0.0f64; 17];
+    let mut y = [0.0f64; 17];
+    for i in 0..17 {
+        x[i] = -2.0 + i as f64 * 0.25;
+        y[i] = tithi::lunar_phase(eph, start + x[i]);
+    }
+    unwrap_angles(&mut y);
+    let y0 = inverse_lagrange(&x, &y, 17, 180.0);
+    start + y0
+}
+
+/// Mirror of `new_moon_after` targeting the following full moon (Purnima,
+/// phase 180°), used for Purnimanta month boundaries.
+fn full_moon_after(eph: &mut Ephemeris, jd_ut: f64, tithi_hint: i32) -> f64 {
+    let days_until = ((15 - tithi_hint).rem_euclid(30)) as f64;
+    let start = jd_ut + days_until;
+    let mut x = [0.0f64; 17];
+    let mut y = [0.0f64; 17];
+    for i in 0..17 {
+        x[i] = -2.0 + i as f64 * 0.25;
+        y[i] = tithi::lunar_phase(eph, start + x[i]);
+    }
+    unwrap_angles(&mut y);
+    let y0 = inverse_lagrange(&x, &y, 17, 180.0);
+    start + y0
+}
+
 pub fn solar_rashi(eph: &mut Ephemeris, jd_ut: f64) -> i32 {
     let nirayana = eph.solar_longitude_sidereal(jd_ut);
     let mut rashi = (nirayana / 30.0).ceil() as i32;
@@ -62,12 +74,22 @@ pub fn solar_rashi(eph: &mut Ephemeris, jd_ut: f64) -> i32 {
     rashi
 }
 
+/// Resolve the lunisolar month active at sunrise on a Gregorian date, under
+/// the given `ReckoningScheme`.
+///
+/// The Amanta (new-moon-to-new-moon) reckoning is computed first, since
+/// every scheme's adhika-masa test relies on the same solar-rashi
+/// comparison across the Amanta boundaries. Under `PurnimantaNorthern`, the
+/// Krishna paksha half of an Amanta month is renamed to (and rebounded by)
+/// the following month, since that half already "belongs" to the next
+/// Purnimanta month.
 pub fn masa_for_date(
     eph: &mut Ephemeris,
     year: i32,
     month: i32,
     day: i32,
     loc: &Location,
+    scheme: ReckoningScheme,
 ) -> MasaInfo {
     let jd = eph.gregorian_to_jd(year, month, day);
     let mut jd_rise = eph.sunrise_jd(jd, loc);
@@ -87,18 +109,40 @@ pub fn masa_for_date(
 
     let mut masa_num = rashi_last + 1;
     if masa_num > 12 { masa_num -= 12; }
+
+    let (masa_num, jd_start, jd_end) = match scheme {
+        ReckoningScheme::AmantaSouthern | ReckoningScheme::Gujarati => {
+            (masa_num, last_nm, next_nm)
+        }
+        ReckoningScheme::PurnimantaNorthern => {
+            if t > 15 {
+                let mut next = masa_num + 1;
+                if next > 12 { next -= 12; }
+                let last_pm = full_moon_before(eph, jd_rise, t);
+                let next_pm = full_moon_after(eph, jd_rise, t);
+                (next, last_pm, next_pm)
+            } else {
+                let last_pm = full_moon_before(eph, jd_rise, t);
+                let next_pm = full_moon_after(eph, jd_rise, t);
+                (masa_num, last_pm, next_pm)
+            }
+        }
+    };
     let name = MasaName::from_number(masa_num);
 
     let year_saka = hindu_year_saka(eph, jd_rise, masa_num);
-    let year_vikram = hindu_year_vikram(year_saka);
+    let mut year_vikram = hindu_year_vikram(year_saka);
+    if scheme == ReckoningScheme::Gujarati && masa_num < MasaName::Kartika.number() {
+        year_vikram -= 1;
+    }
 
     MasaInfo {
         name,
         is_adhika,
         year_saka,
         year_vikram,
-        jd_start: last_nm,
-        jd_end: next_nm,
+        jd_start,
+        jd_end,
     }
 }
 
@@ -113,3 +157,15 @@ pub fn hindu_year_saka(eph: &mut Ephemeris, jd_ut: f64, masa_num: i32) -> i32 {
 pub fn hindu_year_vikram(saka_year: i32) -> i32 {
     saka_year + 135
 }
+
+/// Kali Yuga era year, which simply runs ahead of Saka by the epoch offset
+/// baked into `hindu_year_saka`'s `kali - 3179` computation.
+pub fn kali_yuga_year(saka_year: i32) -> i32 {
+    saka_year + 3179
+}
+
+/// Index (1-60) into `SAMVATSARA_NAMES` for the Jupiter-cycle year covering
+/// Gregorian year `gregorian_year`, per the `(year - 1568) % 60` convention.
+pub fn samvatsara_index(gregorian_year: i32) -> i32 {
+    (gregorian_year - 1568).rem_euclid(60) + 1
+}
@@ -0,0 +1,165 @@
+use std::fmt;
+
+use crate::ephemeris::Ephemeris;
+use crate::model::Location;
+
+const DEG2RAD: f64 = std::f64::consts::PI / 180.0;
+const RAD2DEG: f64 = 180.0 / std::f64::consts::PI;
+
+/// A planetary lord. Variants are in weekday order (Sun first); the Chaldean
+/// hora sequence is `CHALDEAN`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Graha {
+    Sun,
+    Moon,
+    Mars,
+    Mercury,
+    Jupiter,
+    Venus,
+    Saturn,
+}
+
+impl fmt::Display for Graha {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Graha::Sun => "Sun",
+            Graha::Moon => "Moon",
+            Graha::Mars => "Mars",
+            Graha::Mercury => "Mercury",
+            Graha::Jupiter => "Jupiter",
+            Graha::Venus => "Venus",
+            Graha::Saturn => "Saturn",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Chaldean order, the sequence in which hora rulership cycles.
+const CHALDEAN: [Graha; 7] = [
+    Graha::Saturn, Graha::Jupiter, Graha::Mars, Graha::Sun,
+    Graha::Venus, Graha::Mercury, Graha::Moon,
+];
+
+/// Ruling planet of the day, indexed Mon=0..Sun=6 (matches `day_of_week`).
+const DAY_LORDS: [Graha; 7] = [
+    Graha::Moon, Graha::Mars, Graha::Mercury, Graha::Jupiter,
+    Graha::Venus, Graha::Saturn, Graha::Sun,
+];
+
+/// Segment index (1-8) of Rahu Kalam, indexed Mon=0..Sun=6.
+const RAHU_KALAM_PART: [i32; 7] = [2, 7, 5, 6, 4, 3, 8];
+/// Segment index (1-8) of Yamaganda, indexed Mon=0..Sun=6.
+const YAMAGANDA_PART: [i32; 7] = [4, 3, 2, 1, 7, 6, 5];
+/// Segment index (1-8) of Gulika Kalam, indexed Mon=0..Sun=6.
+const GULIKA_PART: [i32; 7] = [6, 5, 4, 3, 2, 1, 7];
+
+/// Start/end of an inauspicious (or planetary-hour) day segment, as JDs.
+#[derive(Debug, Clone, Copy)]
+pub struct TimeSpan {
+    pub jd_start: f64,
+    pub jd_end: f64,
+}
+
+/// One of the 24 planetary hours (hora) spanning a civil day.
+#[derive(Debug, Clone, Copy)]
+pub struct Hora {
+    pub lord: Graha,
+    pub jd_start: f64,
+    pub jd_end: f64,
+}
+
+fn trikalam_segment(sunrise: f64, sunset: f64, part: i32) -> TimeSpan {
+    let step = (sunset - sunrise) / 8.0;
+    TimeSpan {
+        jd_start: sunrise + (part - 1) as f64 * step,
+        jd_end: sunrise + part as f64 * step,
+    }
+}
+
+/// Rahu Kalam for the civil day starting at `jd_ut`, at `loc`.
+pub fn rahu_kalam(eph: &mut Ephemeris, jd_ut: f64, loc: &Location) -> TimeSpan {
+    let sunrise = eph.sunrise_jd(jd_ut, loc);
+    let sunset = eph.sunset_jd(jd_ut, loc);
+    let dow = eph.day_of_week(jd_ut) as usize;
+    trikalam_segment(sunrise, sunset, RAHU_KALAM_PART[dow])
+}
+
+/// Yamaganda for the civil day starting at `jd_ut`, at `loc`.
+pub fn yamaganda(eph: &mut Ephemeris, jd_ut: f64, loc: &Location) -> TimeSpan {
+    let sunrise = eph.sunrise_jd(jd_ut, loc);
+    let sunset = eph.sunset_jd(jd_ut, loc);
+    let dow = eph.day_of_week(jd_ut) as usize;
+    trikalam_segment(sunrise, sunset, YAMAGANDA_PART[dow])
+}
+
+/// Gulika Kalam for the civil day starting at `jd_ut`, at `loc`.
+pub fn gulika_kalam(eph: &mut Ephemeris, jd_ut: f64, loc: &Location) -> TimeSpan {
+    let sunrise = eph.sunrise_jd(jd_ut, loc);
+    let sunset = eph.sunset_jd(jd_ut, loc);
+    let dow = eph.day_of_week(jd_ut) as usize;
+    trikalam_segment(sunrise, sunset, GULIKA_PART[dow])
+}
+
+/// The 24 planetary hours (12 by day, 12 by night) covering the civil day
+/// starting at `jd_ut`, in Chaldean order starting from the day's lord.
+pub fn horas_for_day(eph: &mut Ephemeris, jd_ut: f64, loc: &Location) -> Vec<Hora> {
+    let sunrise = eph.sunrise_jd(jd_ut, loc);
+    let sunset = eph.sunset_jd(jd_ut, loc);
+    let next_sunrise = eph.sunrise_jd(jd_ut + 1.0, loc);
+
+    let dow = eph.day_of_week(jd_ut) as usize;
+    let day_lord = DAY_LORDS[dow];
+    let start_idx = CHALDEAN.iter().position(|&g| g == day_lord).unwrap();
+
+    let mut horas = Vec::with_capacity(24);
+
+    let day_step = (sunset - sunrise) / 12.0;
+    for i in 0..12 {
+        horas.push(Hora {
+            lord: CHALDEAN[(start_idx + i) % 7],
+            jd_start: sunrise + i as f64 * day_step,
+            jd_end: sunrise + (i + 1) as f64 * day_step,
+        });
+    }
+
+    let night_step = (next_sunrise - sunset) / 12.0;
+    for i in 0..12 {
+        horas.push(Hora {
+            lord: CHALDEAN[(start_idx + 12 + i) % 7],
+            jd_start: sunset + i as f64 * night_step,
+            jd_end: sunset + (i + 1) as f64 * night_step,
+        });
+    }
+
+    horas
+}
+
+/// The hora active at `jd_ut`, among those computed by `horas_for_day`.
+pub fn hora_at(horas: &[Hora], jd_ut: f64) -> Option<Hora> {
+    horas.iter().copied().find(|h| jd_ut >= h.jd_start && jd_ut < h.jd_end)
+}
+
+/// Sidereal ecliptic longitude of the Lagna (ascendant) at `jd_ut` for
+/// `loc`: the point of the ecliptic rising on the eastern horizon, needed to
+/// anchor a horoscope/muhurta chart on this panchanga core.
+///
+/// Local apparent sidereal time gives the tropical ascendant via the
+/// standard formula; subtracting `ayanamsa(jd_ut)` lands it in the same
+/// sidereal frame as `solar_longitude_sidereal`/`lunar_longitude_sidereal`.
+pub fn ascendant(eph: &mut Ephemeris, jd_ut: f64, loc: &Location) -> f64 {
+    use crate::ephemeris::{ayanamsa, sun};
+
+    let theta = eph.local_sidereal_time(jd_ut, loc) * DEG2RAD;
+    let jd_tt = jd_ut + sun::delta_t_days(jd_ut);
+    let eps = ayanamsa::obliquity_true(jd_tt);
+    let phi = loc.latitude * DEG2RAD;
+
+    let mut tropical = theta.cos().atan2(-(theta.sin() * eps.cos() + phi.tan() * eps.sin())) * RAD2DEG;
+    tropical %= 360.0;
+    if tropical < 0.0 { tropical += 360.0; }
+
+    let mut sidereal = tropical - eph.ayanamsa(jd_ut);
+    sidereal %= 360.0;
+    if sidereal < 0.0 { sidereal += 360.0; }
+    sidereal
+}
@@ -1,6 +1,9 @@
 use crate::ephemeris::Ephemeris;
+use crate::ephemeris::star_catalog;
 use crate::model::*;
-use super::{tithi, masa};
+use super::{tithi, masa, angam, muhurta, eclipse};
+use super::angam::AngamKind;
+use super::eclipse::EclipseKind;
 
 fn days_in_month(year: i32, month: i32) -> i32 {
     const MDAYS: [i32; 13] = [0, 31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
@@ -18,9 +21,22 @@ pub fn gregorian_to_hindu(
     month: i32,
     day: i32,
     loc: &Location,
+) -> HinduDate {
+    gregorian_to_hindu_scheme(eph, year, month, day, loc, ReckoningScheme::AmantaSouthern)
+}
+
+/// Like `gregorian_to_hindu`, but resolving the month name under a chosen
+/// `ReckoningScheme` (Amanta/Purnimanta/regional).
+pub fn gregorian_to_hindu_scheme(
+    eph: &mut Ephemeris,
+    year: i32,
+    month: i32,
+    day: i32,
+    loc: &Location,
+    scheme: ReckoningScheme,
 ) -> HinduDate {
     let ti = tithi::tithi_at_sunrise(eph, year, month, day, loc);
-    let mi = masa::masa_for_date(eph, year, month, day, loc);
+    let mi = masa::masa_for_date(eph, year, month, day, loc, scheme);
 
     let is_adhika_tithi = if day > 1 {
         let ti_prev = tithi::tithi_at_sunrise(eph, year, month, day - 1, loc);
@@ -37,9 +53,150 @@ pub fn gregorian_to_hindu(
         paksha: ti.paksha,
         tithi: ti.paksha_tithi,
         is_adhika_tithi,
+        samvatsara: masa::samvatsara_index(year),
     }
 }
 
+/// Inverse of `gregorian_to_hindu`: the Gregorian date(s) on which `target`
+/// falls at sunrise, searching under `scheme`.
+///
+/// Brackets the search using the same Saka-year/ahargana formula
+/// `masa::hindu_year_saka` uses in reverse to estimate a starting JD, then
+/// scans a window of days around it comparing Saka year, masa, adhika-masa
+/// flag, paksha and tithi (not just the tithi number). `is_adhika_tithi` is
+/// not compared. A kshaya tithi that is never a sunrise tithi yields an
+/// empty result; an adhika tithi (same tithi on two consecutive sunrises)
+/// yields two.
+pub fn hindu_to_gregorian(
+    eph: &mut Ephemeris,
+    target: &HinduDate,
+    loc: &Location,
+    scheme: ReckoningScheme,
+) -> Vec<(i32, i32, i32)> {
+    const SIDEREAL_YEAR: f64 = 365.25636;
+    let kali = target.year_saka + 3179;
+    let masa_num = target.masa.number();
+    let jd_estimate = kali as f64 * SIDEREAL_YEAR - (4 - masa_num) as f64 * 30.0 + 588465.5;
+
+    let mut matches = Vec::new();
+    let window = 200;
+    for offset in -window..=window {
+        let jd = (jd_estimate + offset as f64).floor();
+        let (y, m, d) = eph.jd_to_gregorian(jd);
+        let hd = gregorian_to_hindu_scheme(eph, y, m, d, loc, scheme);
+
+        if hd.year_saka == target.year_saka
+            && hd.masa == target.masa
+            && hd.is_adhika_masa == target.is_adhika_masa
+            && hd.paksha == target.paksha
+            && hd.tithi == target.tithi
+        {
+            matches.push((y, m, d));
+        }
+    }
+
+    matches
+}
+
+/// Convenience overload of `hindu_to_gregorian` taking the lunar date's
+/// fields directly instead of a pre-built `HinduDate` (as `HinduDateParts`,
+/// for callers that only know e.g. "Saka 1946 Bhadrapada Krishna Ashtami")
+/// and not whether that tithi repeats (adhika tithi), a flag the search
+/// does not compare.
+pub fn hindu_to_gregorian_parts(
+    eph: &mut Ephemeris,
+    parts: HinduDateParts,
+    loc: &Location,
+    scheme: ReckoningScheme,
+) -> Vec<(i32, i32, i32)> {
+    let target = HinduDate {
+        year_saka: parts.year_saka,
+        year_vikram: hindu_year_vikram_for_scheme(parts.year_saka, parts.masa, scheme),
+        masa: parts.masa,
+        is_adhika_masa: parts.is_adhika_masa,
+        paksha: parts.paksha,
+        tithi: parts.tithi,
+        is_adhika_tithi: false,
+        samvatsara: 0, // not compared by hindu_to_gregorian
+    };
+    hindu_to_gregorian(eph, &target, loc, scheme)
+}
+
+fn hindu_year_vikram_for_scheme(year_saka: i32, masa: MasaName, scheme: ReckoningScheme) -> i32 {
+    let mut year_vikram = masa::hindu_year_vikram(year_saka);
+    if scheme == ReckoningScheme::Gujarati && masa.number() < MasaName::Kartika.number() {
+        year_vikram -= 1;
+    }
+    year_vikram
+}
+
+fn anga_span_at(eph: &mut Ephemeris, jd_rise: f64, kind: AngamKind) -> AngaInfo {
+    let (index, jd_start, jd_end) = angam::angam_span(eph, jd_rise, kind);
+    AngaInfo { index, jd_start, jd_end }
+}
+
+/// Cross-check `day.nakshatra`'s idealized-grid index against the yogatara
+/// catalog: the nakshatra whose own junction star is angularly nearest the
+/// Moon at the same sunrise instant. The two agree except right around a
+/// grid boundary, where they can differ by one — exactly the
+/// boundary-sensitive dates a star-based check exists to catch.
+pub fn nakshatra_by_yogatara(eph: &mut Ephemeris, day: &PanchangDay) -> i32 {
+    let moon_sidereal = eph.lunar_longitude_sidereal(day.jd_sunrise);
+    star_catalog::nakshatra_by_yogatara(eph, day.jd_sunrise, moon_sidereal)
+}
+
+pub fn panchang_for_day(
+    eph: &mut Ephemeris,
+    year: i32,
+    month: i32,
+    day: i32,
+    loc: &Location,
+) -> PanchangDay {
+    let jd = eph.gregorian_to_jd(year, month, day);
+    let jd_sunrise = eph.sunrise_jd(jd, loc);
+    let ti = tithi::tithi_at_sunrise(eph, year, month, day, loc);
+    let hd = gregorian_to_hindu(eph, year, month, day, loc);
+
+    let jd_rise = if jd_sunrise > 0.0 { jd_sunrise } else { jd + 0.5 - loc.utc_offset / 24.0 };
+    let nakshatra = anga_span_at(eph, jd_rise, AngamKind::Nakshatra);
+    let yoga = anga_span_at(eph, jd_rise, AngamKind::Yoga);
+    let karana = anga_span_at(eph, jd_rise, AngamKind::Karana);
+    let vara = eph.day_of_week(jd);
+    let eclipse = eclipse_note_at(eph, &ti);
+
+    PanchangDay {
+        greg_year: year,
+        greg_month: month,
+        greg_day: day,
+        jd_sunrise,
+        hindu_date: hd,
+        tithi: ti,
+        nakshatra,
+        yoga,
+        karana,
+        vara,
+        eclipse,
+    }
+}
+
+/// Flag a candidate eclipse on Amavasya (solar, tithi 30) or Purnima (lunar,
+/// tithi 15): the syzygy instant is exactly the tithi's `jd_end` (the phase
+/// crossing 360°/180° respectively), so no separate search is needed.
+fn eclipse_note_at(eph: &mut Ephemeris, ti: &TithiInfo) -> Option<EclipseNote> {
+    let kind = match ti.tithi_num {
+        15 => EclipseKind::Lunar,
+        30 => EclipseKind::Solar,
+        _ => return None,
+    };
+
+    let event = eclipse::eclipse_candidate(eph, ti.jd_end, kind)?;
+    Some(EclipseNote {
+        is_solar: kind == EclipseKind::Solar,
+        max_jd: event.max_jd,
+        is_total: event.total_begin.is_some(),
+    })
+}
+
 pub fn generate_month_panchang(
     eph: &mut Ephemeris,
     year: i32,
@@ -50,19 +207,7 @@ pub fn generate_month_panchang(
     let mut days = Vec::with_capacity(ndays as usize);
 
     for d in 1..=ndays {
-        let jd = eph.gregorian_to_jd(year, month, d);
-        let jd_sunrise = eph.sunrise_jd(jd, loc);
-        let ti = tithi::tithi_at_sunrise(eph, year, month, d, loc);
-        let hd = gregorian_to_hindu(eph, year, month, d, loc);
-
-        days.push(PanchangDay {
-            greg_year: year,
-            greg_month: month,
-            greg_day: d,
-            jd_sunrise,
-            hindu_date: hd,
-            tithi: ti,
-        });
+        days.push(panchang_for_day(eph, year, month, d, loc));
     }
 
     days
@@ -84,6 +229,11 @@ pub fn jd_to_local_time(jd_ut: f64, utc_offset: f64) -> (i32, i32, i32) {
 pub fn print_month_panchang(eph: &Ephemeris, days: &[PanchangDay], utc_offset: f64) {
     if days.is_empty() { return; }
 
+    let hd0 = days[0].hindu_date;
+    println!("Vikram {}, Saka {}, Kali {}, {} Samvatsara\n",
+        hd0.year_vikram, hd0.year_saka, masa::kali_yuga_year(hd0.year_saka),
+        SAMVATSARA_NAMES[hd0.samvatsara as usize]);
+
     println!("{:<12} {:<5} {:<10} {:<28} {}",
         "Date", "Day", "Sunrise", "Tithi", "Hindu Date");
     println!("{:<12} {:<5} {:<10} {:<28} {}",
@@ -114,16 +264,53 @@ pub fn print_month_panchang(eph: &Ephemeris, days: &[PanchangDay], utc_offset: f
 
         let pk_char = if pd.tithi.paksha == Paksha::Shukla { "S" } else { "K" };
 
-        println!("{:04}-{:02}-{:02}   {:<5} {:02}:{:02}:{:02}   {:<6} {:<13} ({}-{})   {}{} {} {}, Saka {}",
+        let eclipse_note = match pd.eclipse {
+            Some(e) if e.is_solar => "   * Solar Eclipse",
+            Some(_) => "   * Lunar Eclipse",
+            None => "",
+        };
+
+        println!("{:04}-{:02}-{:02}   {:<5} {:02}:{:02}:{:02}   {:<6} {:<13} ({}-{})   {}{} {} {}, Saka {}   {} / {} / {}{}",
             pd.greg_year, pd.greg_month, pd.greg_day,
             DOW_SHORT[dow as usize],
             sh, sm, ss,
             paksha_str, tithi_name, pk_char, pt,
             adhika_prefix, masa_str, paksha_str, pt,
-            pd.hindu_date.year_saka);
+            pd.hindu_date.year_saka,
+            NAKSHATRA_NAMES[pd.nakshatra.index as usize],
+            YOGA_NAMES[pd.yoga.index as usize],
+            angam::karana_name(pd.karana.index),
+            eclipse_note);
     }
 }
 
+/// Print the day panchang followed by the Rahu Kalam/Yamaganda/Gulika
+/// Kalam muhurta windows (what the `-k` CLI flag shows).
+pub fn print_day_panchang_with_muhurta(
+    eph: &mut Ephemeris,
+    day: &PanchangDay,
+    loc: &Location,
+) {
+    print_day_panchang(eph, day, loc.utc_offset);
+
+    let jd = eph.gregorian_to_jd(day.greg_year, day.greg_month, day.greg_day);
+    let rahu = muhurta::rahu_kalam(eph, jd, loc);
+    let yama = muhurta::yamaganda(eph, jd, loc);
+    let gulika = muhurta::gulika_kalam(eph, jd, loc);
+
+    let (rh, rm, rs) = jd_to_local_time(rahu.jd_start, loc.utc_offset);
+    let (rh2, rm2, rs2) = jd_to_local_time(rahu.jd_end, loc.utc_offset);
+    println!("Rahu Kalam:  {:02}:{:02}:{:02} - {:02}:{:02}:{:02}", rh, rm, rs, rh2, rm2, rs2);
+
+    let (yh, ym, ys) = jd_to_local_time(yama.jd_start, loc.utc_offset);
+    let (yh2, ym2, ys2) = jd_to_local_time(yama.jd_end, loc.utc_offset);
+    println!("Yamaganda:   {:02}:{:02}:{:02} - {:02}:{:02}:{:02}", yh, ym, ys, yh2, ym2, ys2);
+
+    let (gh, gm, gs) = jd_to_local_time(gulika.jd_start, loc.utc_offset);
+    let (gh2, gm2, gs2) = jd_to_local_time(gulika.jd_end, loc.utc_offset);
+    println!("Gulika Kalam: {:02}:{:02}:{:02} - {:02}:{:02}:{:02}", gh, gm, gs, gh2, gm2, gs2);
+}
+
 pub fn print_day_panchang(eph: &Ephemeris, day: &PanchangDay, utc_offset: f64) {
     let jd = eph.gregorian_to_jd(day.greg_year, day.greg_month, day.greg_day);
     let dow = eph.day_of_week(jd);
@@ -150,14 +337,33 @@ pub fn print_day_panchang(eph: &Ephemeris, day: &PanchangDay, utc_offset: f64) {
         day.greg_year, day.greg_month, day.greg_day,
         DOW_NAMES[dow as usize]);
     println!("Sunrise:    {:02}:{:02}:{:02} IST", sh, sm, ss);
-    println!("Tithi:      {} {} ({}-{})", paksha_str, tithi_name, pk_char, pt);
-    println!("Hindu Date: {}{} {} {}, Saka {} (Vikram {})",
+    println!("Vara:       {}", DOW_NAMES[day.vara as usize]);
+
+    let (th, tm, ts) = jd_to_local_time(day.tithi.jd_end, utc_offset);
+    println!("Tithi:      {} {} ({}-{}) until {:02}:{:02}:{:02}", paksha_str, tithi_name, pk_char, pt, th, tm, ts);
+
+    let (nh, nm, ns) = jd_to_local_time(day.nakshatra.jd_end, utc_offset);
+    println!("Nakshatra:  {} until {:02}:{:02}:{:02}", NAKSHATRA_NAMES[day.nakshatra.index as usize], nh, nm, ns);
+
+    let (yh, ym, ys) = jd_to_local_time(day.yoga.jd_end, utc_offset);
+    println!("Yoga:       {} until {:02}:{:02}:{:02}", YOGA_NAMES[day.yoga.index as usize], yh, ym, ys);
+
+    let (kh, km, ks) = jd_to_local_time(day.karana.jd_end, utc_offset);
+    println!("Karana:     {} until {:02}:{:02}:{:02}", angam::karana_name(day.karana.index), kh, km, ks);
+    println!("Hindu Date: {}{} {} {}, Saka {} (Vikram {}, Kali {}), {} Samvatsara",
         adhika_prefix, masa_str, paksha_str, pt,
-        day.hindu_date.year_saka, day.hindu_date.year_vikram);
+        day.hindu_date.year_saka, day.hindu_date.year_vikram,
+        masa::kali_yuga_year(day.hindu_date.year_saka),
+        SAMVATSARA_NAMES[day.hindu_date.samvatsara as usize]);
     if day.tithi.is_kshaya {
         println!("Note:       Kshaya tithi (next tithi is skipped)");
     }
     if day.hindu_date.is_adhika_tithi {
         println!("Note:       Adhika tithi (same tithi as previous day)");
     }
+    if let Some(e) = day.eclipse {
+        let kind_str = if e.is_solar { "Solar" } else { "Lunar" };
+        let totality = if e.is_total { "total" } else { "partial/penumbral" };
+        println!("Eclipse:    {} eclipse candidate ({})", kind_str, totality);
+    }
 }
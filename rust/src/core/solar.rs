@@ -1,3 +1,16 @@
+//! Tamil/Bengali/Odia/Malayalam solar calendars.
+//!
+//! A solar month begins at sankranti — the moment the Sun's sidereal
+//! longitude crosses a multiple of 30° into the next rashi, found by
+//! `sankranti_jd`'s bisection. The four regional calendars agree on that
+//! moment but differ on which *civil* day hosts the new month, each using
+//! its own "critical time" rule (`critical_time_jd`): Tamil uses a sunset
+//! rule, Malayalam an aparahna (~60% of daytime elapsed) rule, and
+//! Bengali/Odia a midnight/sunrise-anchored rule (with Bengali additionally
+//! overriding via the prevailing tithi when the sankranti falls very close
+//! to the boundary). `gregorian_to_solar` applies the relevant rule and
+//! returns the resulting `SolarDate`.
+
 use crate::ephemeris::Ephemeris;
 use crate::model::*;
 use super::tithi;
@@ -9,12 +9,36 @@ pub fn lunar_phase(eph: &mut Ephemeris, jd_ut: f64) -> f64 {
     phase
 }
 
+/// Topocentric counterpart of `lunar_phase`: uses the Moon's longitude as
+/// seen from `loc` (corrected for the ~0.95° horizontal parallax) in place
+/// of the geocentric longitude.
+pub fn lunar_phase_topocentric(eph: &mut Ephemeris, jd_ut: f64, loc: &Location) -> f64 {
+    let moon = eph.lunar_longitude_topocentric(jd_ut, loc);
+    let sun = eph.solar_longitude(jd_ut);
+    let mut phase = (moon - sun) % 360.0;
+    if phase < 0.0 { phase += 360.0; }
+    phase
+}
+
+fn lunar_phase_in(eph: &mut Ephemeris, jd_ut: f64, loc: &Location, frame: LunarFrame) -> f64 {
+    match frame {
+        LunarFrame::Geocentric => lunar_phase(eph, jd_ut),
+        LunarFrame::Topocentric => lunar_phase_topocentric(eph, jd_ut, loc),
+    }
+}
+
 pub fn tithi_at_moment(eph: &mut Ephemeris, jd_ut: f64) -> i32 {
     let phase = lunar_phase(eph, jd_ut);
     let t = (phase / 12.0) as i32 + 1;
     if t > 30 { 30 } else { t }
 }
 
+fn tithi_at_moment_in(eph: &mut Ephemeris, jd_ut: f64, loc: &Location, frame: LunarFrame) -> i32 {
+    let phase = lunar_phase_in(eph, jd_ut, loc, frame);
+    let t = (phase / 12.0) as i32 + 1;
+    if t > 30 { 30 } else { t }
+}
+
 pub fn find_tithi_boundary(
     eph: &mut Ephemeris,
     jd_start: f64,
@@ -42,12 +66,45 @@ pub fn find_tithi_boundary(
     (lo + hi) / 2.0
 }
 
-pub fn tithi_at_sunrise(
+fn find_tithi_boundary_in(
+    eph: &mut Ephemeris,
+    jd_start: f64,
+    jd_end: f64,
+    target_tithi: i32,
+    loc: &Location,
+    frame: LunarFrame,
+) -> f64 {
+    let target_phase = (target_tithi - 1) as f64 * 12.0;
+    let mut lo = jd_start;
+    let mut hi = jd_end;
+
+    for _ in 0..50 {
+        let mid = (lo + hi) / 2.0;
+        let phase = lunar_phase_in(eph, mid, loc, frame);
+        let mut diff = phase - target_phase;
+        if diff > 180.0 { diff -= 360.0; }
+        if diff < -180.0 { diff += 360.0; }
+
+        if diff >= 0.0 {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+
+    (lo + hi) / 2.0
+}
+
+/// Tithi active at sunrise, under the given `LunarFrame`. `tithi_at_sunrise`
+/// delegates here with `LunarFrame::Geocentric`; pass `Topocentric` for a
+/// panchanga corrected for the observer's lunar parallax.
+pub fn tithi_at_sunrise_in(
     eph: &mut Ephemeris,
     year: i32,
     month: i32,
     day: i32,
     loc: &Location,
+    frame: LunarFrame,
 ) -> TithiInfo {
     let jd = eph.gregorian_to_jd(year, month, day);
     let mut jd_rise = eph.sunrise_jd(jd, loc);
@@ -56,21 +113,21 @@ pub fn tithi_at_sunrise(
         jd_rise = jd + 0.5 - loc.utc_offset / 24.0;
     }
 
-    let t = tithi_at_moment(eph, jd_rise);
+    let t = tithi_at_moment_in(eph, jd_rise, loc, frame);
 
     let paksha = if t <= 15 { Paksha::Shukla } else { Paksha::Krishna };
     let paksha_tithi = if t <= 15 { t } else { t - 15 };
 
-    let jd_start = find_tithi_boundary(eph, jd_rise - 2.0, jd_rise, t);
+    let jd_start = find_tithi_boundary_in(eph, jd_rise - 2.0, jd_rise, t, loc, frame);
 
     let next_tithi = (t % 30) + 1;
-    let jd_end = find_tithi_boundary(eph, jd_rise, jd_rise + 2.0, next_tithi);
+    let jd_end = find_tithi_boundary_in(eph, jd_rise, jd_rise + 2.0, next_tithi, loc, frame);
 
     // Check for kshaya tithi
     let jd_tomorrow = jd + 1.0;
     let jd_rise_tmrw = eph.sunrise_jd(jd_tomorrow, loc);
     let is_kshaya = if jd_rise_tmrw > 0.0 {
-        let t_tmrw = tithi_at_moment(eph, jd_rise_tmrw);
+        let t_tmrw = tithi_at_moment_in(eph, jd_rise_tmrw, loc, frame);
         let diff = ((t_tmrw - t) + 30) % 30;
         diff > 1
     } else {
@@ -86,3 +143,13 @@ pub fn tithi_at_sunrise(
         is_kshaya,
     }
 }
+
+pub fn tithi_at_sunrise(
+    eph: &mut Ephemeris,
+    year: i32,
+    month: i32,
+    day: i32,
+    loc: &Location,
+) -> TithiInfo {
+    tithi_at_sunrise_in(eph, year, month, day, loc, LunarFrame::Geocentric)
+}
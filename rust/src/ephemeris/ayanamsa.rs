@@ -1,4 +1,4 @@
-/// Lahiri ayanamsa — IAU 1976 precession
+/// Sidereal ayanamsa models — IAU 1976 precession anchored at a reference epoch.
 
 use std::f64::consts::PI;
 use super::sun;
@@ -8,6 +8,47 @@ const RAD2DEG: f64 = 180.0 / PI;
 const J2000: f64 = 2451545.0;
 const LAHIRI_T0: f64 = 2435553.5;
 const LAHIRI_AYAN_T0: f64 = 23.245524743;
+const RAMAN_T0: f64 = 2415020.0;
+const RAMAN_AYAN_T0: f64 = 21.01125;
+const KP_T0: f64 = 2415020.0;
+const KP_AYAN_T0: f64 = 22.46047;
+const FAGAN_BRADLEY_T0: f64 = 2433282.5;
+const FAGAN_BRADLEY_AYAN_T0: f64 = 24.042044444;
+
+/// Selectable sidereal reference frame. Every variant (including `Custom`) is
+/// a fixed ayanamsa value anchored at a reference Julian day and precessed
+/// forward/backward at the standard IAU 1976 rate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Ayanamsha {
+    /// N.C. Lahiri / Chitrapaksha — the traditional Indian government standard.
+    Lahiri,
+    /// B.V. Raman's ayanamsa.
+    Raman,
+    /// Krishnamurti Paddhati.
+    Kp,
+    /// Fagan–Bradley, the Western sidereal astrology standard.
+    FaganBradley,
+    /// Any other reference epoch: `ayan_at_t0` degrees at Julian day `t0_jd`.
+    Custom { t0_jd: f64, ayan_at_t0: f64 },
+}
+
+impl Default for Ayanamsha {
+    fn default() -> Self {
+        Ayanamsha::Lahiri
+    }
+}
+
+impl Ayanamsha {
+    fn epoch(self) -> (f64, f64) {
+        match self {
+            Ayanamsha::Lahiri => (LAHIRI_T0, LAHIRI_AYAN_T0),
+            Ayanamsha::Raman => (RAMAN_T0, RAMAN_AYAN_T0),
+            Ayanamsha::Kp => (KP_T0, KP_AYAN_T0),
+            Ayanamsha::FaganBradley => (FAGAN_BRADLEY_T0, FAGAN_BRADLEY_AYAN_T0),
+            Ayanamsha::Custom { t0_jd, ayan_at_t0 } => (t0_jd, ayan_at_t0),
+        }
+    }
+}
 
 fn iau1976_precession_angles(t: f64) -> (f64, f64, f64) {
     let z_big = ((0.017998 * t + 0.30188) * t + 2306.2181) * t * DEG2RAD / 3600.0;
@@ -19,7 +60,7 @@ fn iau1976_precession_angles(t: f64) -> (f64, f64, f64) {
 /// Precess Cartesian equatorial coordinates.
 /// direction > 0: from J to J2000
 /// direction < 0: from J2000 to J
-fn precess_equatorial(x: &mut [f64; 3], j: f64, direction: i32) {
+pub(crate) fn precess_equatorial(x: &mut [f64; 3], j: f64, direction: i32) {
     if j == J2000 { return; }
 
     let t = (j - J2000) / 36525.0;
@@ -53,7 +94,7 @@ fn precess_equatorial(x: &mut [f64; 3], j: f64, direction: i32) {
     x[2] = r[2];
 }
 
-fn obliquity_iau1976(jd_tt: f64) -> f64 {
+pub(crate) fn obliquity_iau1976(jd_tt: f64) -> f64 {
     let t = (jd_tt - J2000) / 36525.0;
     let u = t / 100.0;
     (23.0 + 26.0 / 60.0 + 21.448 / 3600.0
@@ -66,7 +107,7 @@ fn obliquity_iau1976(jd_tt: f64) -> f64 {
         * DEG2RAD
 }
 
-fn equatorial_to_ecliptic(x: &mut [f64; 3], eps: f64) {
+pub(crate) fn equatorial_to_ecliptic(x: &mut [f64; 3], eps: f64) {
     let c = eps.cos();
     let s = eps.sin();
     let y1 = c * x[1] + s * x[2];
@@ -75,8 +116,63 @@ fn equatorial_to_ecliptic(x: &mut [f64; 3], eps: f64) {
     x[2] = z1;
 }
 
-/// Lahiri ayanamsa in degrees (MEAN, without nutation)
+/// Nutation in longitude and obliquity (Δψ, Δε), in degrees, at `jd_tt`,
+/// from the leading IAU-1980 terms (Moon's node plus the largest
+/// periodic terms in the Sun's and Moon's mean longitudes).
+pub fn nutation(jd_tt: f64) -> (f64, f64) {
+    let t = (jd_tt - J2000) / 36525.0;
+
+    // Longitude of the Moon's mean ascending node, and the Sun's/Moon's mean
+    // longitudes — the arguments of the four largest periodic terms.
+    let omega = 125.04452 - 1934.136261 * t;
+    let l_s = 280.4665 + 36000.7698 * t;
+    let l_m = 218.3165 + 481267.8813 * t;
+
+    let dpsi = (-17.20 * omega.to_radians().sin()
+        - 1.32 * (2.0 * l_s).to_radians().sin()
+        - 0.23 * (2.0 * l_m).to_radians().sin()
+        + 0.21 * (2.0 * omega).to_radians().sin())
+        / 3600.0;
+    let deps = (9.20 * omega.to_radians().cos()
+        + 0.57 * (2.0 * l_s).to_radians().cos()
+        + 0.10 * (2.0 * l_m).to_radians().cos()
+        - 0.09 * (2.0 * omega).to_radians().cos())
+        / 3600.0;
+
+    (dpsi, deps)
+}
+
+/// Apparent (true) obliquity of the ecliptic, radians: the IAU 1976 mean
+/// value plus the nutation-in-obliquity term from `nutation`.
+pub fn obliquity_true(jd_tt: f64) -> f64 {
+    let (_, deps) = nutation(jd_tt);
+    obliquity_iau1976(jd_tt) + deps * DEG2RAD
+}
+
+/// Lahiri ayanamsa in degrees (MEAN, without nutation). Kept for backward
+/// compatibility; equivalent to `ayanamsa_for(jd_ut, Ayanamsha::Lahiri)`.
 pub fn ayanamsa(jd_ut: f64) -> f64 {
+    ayanamsa_for(jd_ut, Ayanamsha::Lahiri)
+}
+
+/// True (apparent) ayanamsa in degrees: the mean value plus the
+/// nutation-in-longitude term projected onto the ecliptic, Δψ·cos(ε), which
+/// is how the apparent sidereal longitude used by most panchangas differs
+/// from the mean one.
+pub fn ayanamsa_true(jd_ut: f64, mode: Ayanamsha) -> f64 {
+    let jd_tt = jd_ut + sun::delta_t_days(jd_ut);
+    let (dpsi, _) = nutation(jd_tt);
+    let eps = obliquity_iau1976(jd_tt);
+
+    let mut ayan = ayanamsa_for(jd_ut, mode) + dpsi * eps.cos();
+    ayan %= 360.0;
+    if ayan < 0.0 { ayan += 360.0; }
+    ayan
+}
+
+/// Ayanamsa in degrees (MEAN, without nutation) under the given sidereal mode.
+pub fn ayanamsa_for(jd_ut: f64, mode: Ayanamsha) -> f64 {
+    let (t0, ayan_t0) = mode.epoch();
     let jd_tt = jd_ut + sun::delta_t_days(jd_ut);
 
     let mut x = [1.0f64, 0.0, 0.0];
@@ -85,16 +181,16 @@ pub fn ayanamsa(jd_ut: f64) -> f64 {
     precess_equatorial(&mut x, jd_tt, 1);
 
     // Precess from J2000 to t0
-    precess_equatorial(&mut x, LAHIRI_T0, -1);
+    precess_equatorial(&mut x, t0, -1);
 
     // Convert to ecliptic of t0
-    let eps_t0 = obliquity_iau1976(LAHIRI_T0);
+    let eps_t0 = obliquity_iau1976(t0);
     equatorial_to_ecliptic(&mut x, eps_t0);
 
     // Get polar longitude
     let lon = x[1].atan2(x[0]) * RAD2DEG;
 
-    let mut ayan = -lon + LAHIRI_AYAN_T0;
+    let mut ayan = -lon + ayan_t0;
 
     // Normalize to [0, 360)
     ayan = ayan % 360.0;
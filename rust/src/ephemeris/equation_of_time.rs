@@ -0,0 +1,30 @@
+/// Equation of time and apparent solar time.
+
+use std::f64::consts::PI;
+use super::sun;
+
+const DEG2RAD: f64 = PI / 180.0;
+
+fn mean_solar_longitude_deg(jd_tt: f64) -> f64 {
+    let t = (jd_tt - 2451545.0) / 36525.0;
+    let mut l0 = 280.4664567 + 36000.76983 * t + 0.0003032 * t * t;
+    l0 %= 360.0;
+    if l0 < 0.0 { l0 += 360.0; }
+    l0
+}
+
+/// Equation of time in minutes (apparent solar time minus mean solar time),
+/// given the apparent right ascension of the Sun at `jd_ut`, in degrees.
+pub fn equation_of_time_minutes(jd_ut: f64, ra_deg: f64) -> f64 {
+    let jd_tt = sun::jd_ut_to_tt(jd_ut);
+    let l0 = mean_solar_longitude_deg(jd_tt);
+    let eps = sun::mean_obliquity(jd_tt) * DEG2RAD;
+    let dpsi = sun::nutation_longitude(jd_ut);
+
+    let mut e_deg = l0 - 0.0057183 - ra_deg + dpsi * eps.cos();
+    e_deg %= 360.0;
+    if e_deg > 180.0 { e_deg -= 360.0; }
+    if e_deg < -180.0 { e_deg += 360.0; }
+
+    e_deg * 4.0
+}
@@ -3,13 +3,21 @@ pub mod sun;
 pub mod moon;
 pub mod ayanamsa;
 pub mod rise;
+pub mod parallax;
+pub mod equation_of_time;
+pub mod star_catalog;
+pub mod planet;
 
 use crate::model::Location;
+pub use ayanamsa::Ayanamsha;
+pub use planet::Planet;
 
 /// Ephemeris facade — owns all mutable computation state.
 pub struct Ephemeris {
     sun_state: sun::SunState,
     moon_state: moon::MoonState,
+    planet_state: planet::PlanetState,
+    ayanamsha: Ayanamsha,
 }
 
 impl Ephemeris {
@@ -17,9 +25,22 @@ impl Ephemeris {
         Ephemeris {
             sun_state: sun::SunState::new(),
             moon_state: moon::MoonState::new(),
+            planet_state: planet::PlanetState::new(),
+            ayanamsha: Ayanamsha::default(),
         }
     }
 
+    /// Select the sidereal reference frame used by `solar_longitude_sidereal`,
+    /// `lunar_longitude_sidereal`, `ayanamsa`, and everything built on them
+    /// (sankranti, masa, angam boundaries).
+    pub fn set_ayanamsha(&mut self, mode: Ayanamsha) {
+        self.ayanamsha = mode;
+    }
+
+    pub fn ayanamsha(&self) -> Ayanamsha {
+        self.ayanamsha
+    }
+
     pub fn gregorian_to_jd(&self, year: i32, month: i32, day: i32) -> f64 {
         julian_day::gregorian_to_jd(year, month, day)
     }
@@ -40,16 +61,86 @@ impl Ephemeris {
         self.moon_state.lunar_longitude(jd_ut)
     }
 
+    /// Tropical lunar longitude in degrees together with the geocentric
+    /// distance in km.
+    pub fn lunar_longitude_and_distance(&mut self, jd_ut: f64) -> (f64, f64) {
+        self.moon_state.lunar_longitude_and_distance(jd_ut)
+    }
+
+    /// Geocentric distance to the Moon, in km.
+    pub fn lunar_distance(&mut self, jd_ut: f64) -> f64 {
+        self.moon_state.lunar_distance(jd_ut)
+    }
+
+    /// Geocentric ecliptic latitude of the Moon, in degrees.
+    pub fn lunar_latitude(&mut self, jd_ut: f64) -> f64 {
+        self.moon_state.lunar_latitude(jd_ut)
+    }
+
+    /// Topocentric lunar longitude for an observer at `loc`, correcting the
+    /// geocentric position for horizontal parallax (~1°) and annual
+    /// aberration (~20.5″). The existing `delta_t_days`/`nutation_longitude`
+    /// calls inside the parallax reduction are unaffected; aberration is
+    /// applied on top of the parallax-corrected longitude.
+    pub fn lunar_longitude_topocentric(&mut self, jd_ut: f64, loc: &Location) -> f64 {
+        let (lon_deg, distance_km) = self.moon_state.lunar_longitude_and_distance(jd_ut);
+        let parallactic = parallax::topocentric_longitude(
+            jd_ut,
+            lon_deg,
+            distance_km,
+            loc.latitude,
+            loc.longitude,
+            loc.altitude,
+        );
+        let sun_lon = self.solar_longitude(jd_ut);
+        let mut apparent = parallactic + parallax::annual_aberration_deg(sun_lon, parallactic);
+        apparent %= 360.0;
+        if apparent < 0.0 { apparent += 360.0; }
+        apparent
+    }
+
     pub fn solar_longitude_sidereal(&mut self, jd_ut: f64) -> f64 {
         let sayana = self.solar_longitude(jd_ut);
-        let ayan = ayanamsa::ayanamsa(jd_ut);
+        let ayan = ayanamsa::ayanamsa_for(jd_ut, self.ayanamsha);
+        let mut nirayana = (sayana - ayan) % 360.0;
+        if nirayana < 0.0 { nirayana += 360.0; }
+        nirayana
+    }
+
+    pub fn lunar_longitude_sidereal(&mut self, jd_ut: f64) -> f64 {
+        let sayana = self.lunar_longitude(jd_ut);
+        let ayan = ayanamsa::ayanamsa_for(jd_ut, self.ayanamsha);
         let mut nirayana = (sayana - ayan) % 360.0;
         if nirayana < 0.0 { nirayana += 360.0; }
         nirayana
     }
 
     pub fn ayanamsa(&self, jd_ut: f64) -> f64 {
-        ayanamsa::ayanamsa(jd_ut)
+        ayanamsa::ayanamsa_for(jd_ut, self.ayanamsha)
+    }
+
+    /// Geocentric tropical longitude of a graha (Mercury..Saturn).
+    pub fn planet_longitude(&mut self, jd_ut: f64, which: planet::Planet) -> f64 {
+        self.planet_state.longitude(jd_ut, which)
+    }
+
+    /// Geocentric sidereal longitude of a graha, under the current ayanamsa.
+    pub fn planet_longitude_sidereal(&mut self, jd_ut: f64, which: planet::Planet) -> f64 {
+        let sayana = self.planet_longitude(jd_ut, which);
+        let ayan = ayanamsa::ayanamsa_for(jd_ut, self.ayanamsha);
+        let mut nirayana = (sayana - ayan) % 360.0;
+        if nirayana < 0.0 { nirayana += 360.0; }
+        nirayana
+    }
+
+    /// Geocentric ecliptic latitude of a graha, in degrees.
+    pub fn planet_latitude(&mut self, jd_ut: f64, which: planet::Planet) -> f64 {
+        self.planet_state.latitude(jd_ut, which)
+    }
+
+    /// Geocentric distance to a graha, in AU.
+    pub fn planet_distance_au(&mut self, jd_ut: f64, which: planet::Planet) -> f64 {
+        self.planet_state.distance_au(jd_ut, which)
     }
 
     pub fn sunrise_jd(&mut self, jd_ut: f64, loc: &Location) -> f64 {
@@ -83,4 +174,23 @@ impl Ephemeris {
     pub fn nutation_longitude(&self, jd_ut: f64) -> f64 {
         sun::nutation_longitude(jd_ut)
     }
+
+    /// Equation of time in minutes (apparent solar time minus mean solar time).
+    pub fn equation_of_time_minutes(&mut self, jd_ut: f64) -> f64 {
+        let ra = self.solar_ra(jd_ut);
+        equation_of_time::equation_of_time_minutes(jd_ut, ra)
+    }
+
+    /// Apparent (true sundial) solar time at `loc`, as a JD: `jd_ut` shifted
+    /// by the site longitude and the equation of time (local time, not UT).
+    pub fn apparent_solar_time_jd(&mut self, jd_ut: f64, loc: &Location) -> f64 {
+        let eot_minutes = self.equation_of_time_minutes(jd_ut);
+        let local_mean_jd = jd_ut + loc.longitude / 360.0;
+        local_mean_jd + eot_minutes / (24.0 * 60.0)
+    }
+
+    /// Local apparent sidereal time at `loc.longitude`, in degrees.
+    pub fn local_sidereal_time(&self, jd_ut: f64, loc: &Location) -> f64 {
+        parallax::local_apparent_sidereal_time_deg(jd_ut, loc.longitude)
+    }
 }
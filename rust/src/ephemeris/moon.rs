@@ -315,6 +315,23 @@ impl MoonState {
                     ans += j * sv;
                     idx += 1; // skip radius
                 }
+                // Radius counterparts of 1/2: same row layout, but skip the
+                // longitude amplitude and accumulate the radius pair instead,
+                // on the cosine of the argument.
+                3 => {
+                    idx += 2; // skip longitude
+                    let j = pt[idx] as f64;
+                    idx += 1;
+                    let k = pt[idx] as f64;
+                    idx += 1;
+                    ans += (10000.0 * j + k) * cv;
+                }
+                4 => {
+                    idx += 1; // skip longitude
+                    let j = pt[idx] as f64;
+                    idx += 1;
+                    ans += j * cv;
+                }
                 _ => {}
             }
         }
@@ -605,6 +622,12 @@ impl MoonState {
 
     /// Compute tropical lunar longitude in degrees [0, 360)
     pub fn lunar_longitude(&mut self, jd_ut: f64) -> f64 {
+        self.lunar_longitude_and_distance(jd_ut).0
+    }
+
+    /// Compute tropical lunar longitude in degrees [0, 360) together with the
+    /// geocentric distance in km, as used by the light-time correction above.
+    pub fn lunar_longitude_and_distance(&mut self, jd_ut: f64) -> (f64, f64) {
         let jd_tt = jd_ut + sun::delta_t_days(jd_ut);
         self.t = (jd_tt - J2000) / 36525.0;
         self.t2 = self.t * self.t;
@@ -615,23 +638,21 @@ impl MoonState {
 
         let mut lon_deg = lon_rad * (180.0 / PI);
 
-        // Distance-dependent light-time correction
-        {
-            let cos_l = (STR * self.mp).cos();
-            let cos_2d_l = (STR * (2.0 * self.d - self.mp)).cos();
-            let cos_2d = (STR * (2.0 * self.d)).cos();
-            let cos_2l = (STR * (2.0 * self.mp)).cos();
-            let cos_lp = (STR * self.m_sun).cos();
-
-            let r_mean = 385000.529;
-            let delta_r = -20905.355 * cos_l
-                - 3699.111 * cos_2d_l
-                - 2955.968 * cos_2d
-                - 569.925 * cos_2l
-                + 48.888 * cos_lp;
-
-            lon_deg -= 0.000196 * (r_mean / (r_mean + delta_r));
-        }
+        // Distance-dependent light-time correction. Every LR row carries a
+        // radius amplitude pair alongside its longitude pair (see the
+        // `idx += 2` skip in `chewm`); summing all 118 via the radius
+        // branch (typflg 3) gives the full main-table perturbation to the
+        // mean distance, at the same `j + k·1e-4` km scale the longitude
+        // branch uses (confirmed by the dominant term: row 1's radius pair
+        // (-20905, -3550) is exactly the historical -20905.355 km
+        // equation-of-center amplitude). The T¹/T² (`LRT`/`LRT2`) radius
+        // refinements are sub-kilometer and are left out, as this theory
+        // elsewhere only carries the main table to that precision.
+        let r_mean = 385000.529;
+        let delta_r = 1.0e-4 * self.chewm(&LR, NLR, 4, 3);
+        let distance_km = r_mean + delta_r;
+
+        lon_deg -= 0.000196 * (r_mean / distance_km);
 
         // Apply nutation
         lon_deg += sun::nutation_longitude(jd_ut);
@@ -640,6 +661,52 @@ impl MoonState {
         lon_deg = lon_deg % 360.0;
         if lon_deg < 0.0 { lon_deg += 360.0; }
 
-        lon_deg
+        (lon_deg, distance_km)
     }
+
+    /// Geocentric distance to the Moon, in km.
+    pub fn lunar_distance(&mut self, jd_ut: f64) -> f64 {
+        self.lunar_longitude_and_distance(jd_ut).1
+    }
+
+    /// Ecliptic latitude of the Moon, in degrees: the dominant I=5.128°
+    /// inclination term plus the next several largest periodic terms of the
+    /// lunar latitude series (the leading run of the classical ~60-term
+    /// `B`-series; see e.g. Meeus Ch. 47 Table 47.B), evaluated against the
+    /// same arcsecond-scaled Delaunay arguments (`self.d`, `self.mp`,
+    /// `self.nf`) `lunar_perturbations` uses for longitude/distance. This
+    /// isn't the full series — the remaining terms are all below a
+    /// hundredth of a degree — so treat this as good to a few arcminutes,
+    /// not a full parallel `chewm` pass over a dedicated `B` table.
+    pub fn lunar_latitude(&mut self, jd_ut: f64) -> f64 {
+        let jd_tt = jd_ut + sun::delta_t_days(jd_ut);
+        self.t = (jd_tt - J2000) / 36525.0;
+        self.t2 = self.t * self.t;
+        self.mean_elements();
+
+        let g = |x: f64| -> f64 { (STR * x).sin() };
+        let (d, mp, nf) = (self.d, self.mp, self.nf);
+
+        5.128122 * g(nf)
+            + 0.280602 * g(mp + nf)
+            + 0.277693 * g(mp - nf)
+            + 0.173237 * g(2.0 * d - nf)
+            + 0.055413 * g(2.0 * d - mp + nf)
+            + 0.046271 * g(2.0 * d - mp - nf)
+            + 0.032573 * g(2.0 * d + nf)
+            + 0.017198 * g(2.0 * mp + nf)
+    }
+}
+
+/// Low-precision mean ecliptic latitude of the Moon, in degrees, from the
+/// dominant I=5.128° inclination term of the argument of latitude alone.
+/// Good to a few degrees — used where the eclipse finder only needs a cheap
+/// node-proximity screen and doesn't want a `MoonState` in hand.
+pub fn lunar_latitude_mean(jd_ut: f64) -> f64 {
+    let jd_tt = jd_ut + sun::delta_t_days(jd_ut);
+    let t = (jd_tt - J2000) / 36525.0;
+    let frac_t = t % 1.0;
+    let nf = mods3600(1739232000.0 * frac_t + 295263.0983 * t
+        - 2.079419901760e-01 * t + 335779.55755);
+    5.128 * (STR * nf).sin()
 }
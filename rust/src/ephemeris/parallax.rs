@@ -0,0 +1,102 @@
+/// Topocentric correction for the Moon's geocentric ecliptic longitude.
+/// Rigorous reduction (Meeus Ch. 40), simplified to longitude-only: the
+/// Moon's ecliptic latitude is taken as 0.
+
+use std::f64::consts::PI;
+use super::{rise, sun, julian_day};
+
+const DEG2RAD: f64 = PI / 180.0;
+const RAD2DEG: f64 = 180.0 / PI;
+const EARTH_RADIUS_KM: f64 = 6378.14;
+/// Constant of aberration (Meeus Ch. 23), arcsec.
+const ABERRATION_CONST_ARCSEC: f64 = 20.49552;
+
+fn normalize_deg(d: f64) -> f64 {
+    let d = d % 360.0;
+    if d < 0.0 { d + 360.0 } else { d }
+}
+
+/// Local apparent sidereal time at `jd_ut` for a site at `lon` (degrees
+/// east), in degrees — the Greenwich apparent sidereal time plus longitude.
+pub fn local_apparent_sidereal_time_deg(jd_ut: f64, lon: f64) -> f64 {
+    normalize_deg(apparent_gst_deg(jd_ut) + lon)
+}
+
+/// Apparent Greenwich sidereal time at `jd_ut`, in degrees.
+fn apparent_gst_deg(jd_ut: f64) -> f64 {
+    let (_, yr, mo, dy) = julian_day::revjul(jd_ut);
+    let jd_0h = julian_day::julday(yr, mo, dy, 0.0);
+    let hours = (jd_ut - jd_0h) * 24.0;
+
+    let mut theta = sidereal_time_0h_plus(jd_0h, hours);
+
+    let dpsi = sun::nutation_longitude(jd_ut);
+    let eps = sun::mean_obliquity(sun::jd_ut_to_tt(jd_ut));
+    theta += dpsi * (eps * DEG2RAD).cos();
+
+    normalize_deg(theta)
+}
+
+fn sidereal_time_0h_plus(jd_0h: f64, hours: f64) -> f64 {
+    rise::sidereal_time_0h(jd_0h) + 360.985647 * hours / 24.0
+}
+
+/// Annual aberration in ecliptic longitude, degrees, for a body at
+/// `body_lon_deg` given the Sun's geometric longitude `sun_lon_deg` — the
+/// classical (Earth-orbital-velocity-driven) shift, independent of the
+/// body's own distance or motion, applied explicitly here rather than
+/// folded into the Moon's light-time/distance term.
+pub fn annual_aberration_deg(sun_lon_deg: f64, body_lon_deg: f64) -> f64 {
+    -(ABERRATION_CONST_ARCSEC / 3600.0) * ((sun_lon_deg - body_lon_deg) * DEG2RAD).cos()
+}
+
+/// Topocentric ecliptic longitude of the Moon at `jd_ut` for an observer at
+/// `lat`/`lon` (degrees) and `alt` (meters), given the geocentric ecliptic
+/// longitude `lon_deg` and distance `distance_km` from `MoonState`.
+pub fn topocentric_longitude(
+    jd_ut: f64,
+    lon_deg: f64,
+    distance_km: f64,
+    lat: f64,
+    lon: f64,
+    alt: f64,
+) -> f64 {
+    let jd_tt = sun::jd_ut_to_tt(jd_ut);
+    let eps = sun::mean_obliquity(jd_tt) * DEG2RAD;
+
+    // Geocentric ecliptic -> equatorial (ecliptic latitude taken as 0).
+    let lon_rad = lon_deg * DEG2RAD;
+    let (x, y, z) = (lon_rad.cos(), lon_rad.sin(), 0.0f64);
+    let xe = x;
+    let ye = y * eps.cos() - z * eps.sin();
+    let ze = y * eps.sin() + z * eps.cos();
+
+    let alpha = ye.atan2(xe);
+    let delta = ze.asin();
+
+    // Observer's geocentric coordinates on a flattened Earth.
+    let phi = lat * DEG2RAD;
+    let u = (0.99664719 * phi.tan()).atan();
+    let rho_sin_phi = 0.99664719 * u.sin() + (alt / 6378140.0) * phi.sin();
+    let rho_cos_phi = u.cos() + (alt / 6378140.0) * phi.cos();
+
+    let hpi = (EARTH_RADIUS_KM / distance_km).asin();
+
+    let lst = local_apparent_sidereal_time_deg(jd_ut, lon) * DEG2RAD;
+    let big_h = lst - alpha;
+
+    let denom = delta.cos() - rho_cos_phi * hpi.sin() * big_h.cos();
+    let d_alpha = (-rho_cos_phi * hpi.sin() * big_h.sin()).atan2(denom);
+    let alpha_prime = alpha + d_alpha;
+    let delta_prime = ((delta.sin() - rho_sin_phi * hpi.sin()) * d_alpha.cos()).atan2(denom);
+
+    // Topocentric equatorial -> ecliptic.
+    let xt = delta_prime.cos() * alpha_prime.cos();
+    let yt = delta_prime.cos() * alpha_prime.sin();
+    let zt = delta_prime.sin();
+
+    let xe2 = xt;
+    let ye2 = yt * eps.cos() + zt * eps.sin();
+
+    normalize_deg(ye2.atan2(xe2) * RAD2DEG)
+}
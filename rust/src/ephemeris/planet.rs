@@ -0,0 +1,252 @@
+/// Geocentric positions of the five naked-eye grahas (Mercury, Venus, Mars,
+/// Jupiter, Saturn), so the crate can eventually produce a full planetary
+/// panchanga rather than just Sun/Moon.
+///
+/// This is a low-precision Keplerian model (Standish's "Keplerian Elements
+/// for Approximate Positions of the Major Planets", valid ~1800-2050 to a
+/// few arcminutes), not the Moshier-style table-driven harmonic series
+/// (`sscc`/`chewm`) `moon.rs` uses: each planet's six osculating elements are
+/// propagated linearly in `T`, Kepler's equation is solved for the true
+/// anomaly, and the heliocentric position is rotated into the J2000 ecliptic
+/// frame. Earth's own elements give the Sun-to-Earth vector needed to go
+/// heliocentric -> geocentric, and general precession carries the geocentric
+/// longitude from the J2000 equinox to the equinox of date. There is no light-time iteration, so this is
+/// an *astrometric*, not apparent, position — adequate for a panchanga's
+/// purposes but not for precise occultation work.
+///
+/// The `sscc`/`chewm` engine wasn't adopted here because its `np > 0`
+/// (harmonic-perturbation) rows need a fitted table of dozens of periodic
+/// terms per planet per quantity (the Moshier/VSOP-style corrections beyond
+/// the secular elements), and this crate has no sourced copy of that table.
+/// The Standish secular elements below, by contrast, are a published,
+/// independently-checkable constant set. Inventing plausible-looking
+/// harmonic coefficients to fill out the table format would make planetary
+/// positions silently wrong in a way tests couldn't catch; propagating the
+/// real secular elements alone, with the gap documented, does not.
+use super::sun;
+
+const DEG2RAD: f64 = std::f64::consts::PI / 180.0;
+const RAD2DEG: f64 = 180.0 / std::f64::consts::PI;
+const J2000: f64 = 2451545.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Planet {
+    Mercury,
+    Venus,
+    Mars,
+    Jupiter,
+    Saturn,
+}
+
+const PLANET_COUNT: usize = 5;
+
+impl Planet {
+    fn index(self) -> usize {
+        match self {
+            Planet::Mercury => 0,
+            Planet::Venus => 1,
+            Planet::Mars => 2,
+            Planet::Jupiter => 3,
+            Planet::Saturn => 4,
+        }
+    }
+}
+
+/// Osculating elements at J2000 and their rates per Julian century:
+/// semi-major axis (AU), eccentricity, inclination, mean longitude,
+/// longitude of perihelion, longitude of ascending node — all angles in
+/// degrees.
+#[derive(Debug, Clone, Copy)]
+struct Elements {
+    a0: f64, a_dot: f64,
+    e0: f64, e_dot: f64,
+    i0: f64, i_dot: f64,
+    l0: f64, l_dot: f64,
+    peri0: f64, peri_dot: f64,
+    node0: f64, node_dot: f64,
+}
+
+const EARTH: Elements = Elements {
+    a0: 1.00000261, a_dot: 0.00000562,
+    e0: 0.01671123, e_dot: -0.00004392,
+    i0: -0.00001531, i_dot: -0.01294668,
+    l0: 100.46457166, l_dot: 35999.37244981,
+    peri0: 102.93768193, peri_dot: 0.32327364,
+    node0: 0.0, node_dot: 0.0,
+};
+
+fn elements_for(planet: Planet) -> Elements {
+    match planet {
+        Planet::Mercury => Elements {
+            a0: 0.38709927, a_dot: 0.00000037,
+            e0: 0.20563593, e_dot: 0.00001906,
+            i0: 7.00497902, i_dot: -0.00594749,
+            l0: 252.25032350, l_dot: 149472.67411175,
+            peri0: 77.45779628, peri_dot: 0.16047689,
+            node0: 48.33076593, node_dot: -0.12534081,
+        },
+        Planet::Venus => Elements {
+            a0: 0.72333566, a_dot: 0.00000390,
+            e0: 0.00677672, e_dot: -0.00004107,
+            i0: 3.39467605, i_dot: -0.00078890,
+            l0: 181.97909950, l_dot: 58517.81538729,
+            peri0: 131.60246718, peri_dot: 0.00268329,
+            node0: 76.67984255, node_dot: -0.27769418,
+        },
+        Planet::Mars => Elements {
+            a0: 1.52371034, a_dot: 0.00001847,
+            e0: 0.09339410, e_dot: 0.00007882,
+            i0: 1.84969142, i_dot: -0.00813131,
+            l0: -4.55343205, l_dot: 19140.30268499,
+            peri0: -23.94362959, peri_dot: 0.44441088,
+            node0: 49.55953891, node_dot: -0.29257343,
+        },
+        Planet::Jupiter => Elements {
+            a0: 5.20288700, a_dot: -0.00011607,
+            e0: 0.04838624, e_dot: -0.00013253,
+            i0: 1.30439695, i_dot: -0.00183714,
+            l0: 34.39644051, l_dot: 3034.74612775,
+            peri0: 14.72847983, peri_dot: 0.21252668,
+            node0: 100.47390909, node_dot: 0.20469106,
+        },
+        Planet::Saturn => Elements {
+            a0: 9.53667594, a_dot: -0.00125060,
+            e0: 0.05386179, e_dot: -0.00050991,
+            i0: 2.48599187, i_dot: 0.00193609,
+            l0: 49.95424423, l_dot: 1222.49362201,
+            peri0: 92.59887831, peri_dot: -0.41897216,
+            node0: 113.66242448, node_dot: -0.28867794,
+        },
+    }
+}
+
+/// Eccentric anomaly (radians) solving Kepler's equation `E - e sin E = M`
+/// by Newton-Raphson, starting from `M` itself.
+fn solve_kepler(m_rad: f64, e: f64) -> f64 {
+    let mut ecc = m_rad;
+    for _ in 0..10 {
+        let f = ecc - e * ecc.sin() - m_rad;
+        let f_prime = 1.0 - e * ecc.cos();
+        ecc -= f / f_prime;
+    }
+    ecc
+}
+
+/// Heliocentric J2000-ecliptic Cartesian position (AU) of a body with the
+/// given elements, at `t` Julian centuries since J2000.
+fn heliocentric_position(el: &Elements, t: f64) -> [f64; 3] {
+    let a = el.a0 + el.a_dot * t;
+    let e = el.e0 + el.e_dot * t;
+    let i = (el.i0 + el.i_dot * t) * DEG2RAD;
+    let l = el.l0 + el.l_dot * t;
+    let peri = el.peri0 + el.peri_dot * t;
+    let node = el.node0 + el.node_dot * t;
+
+    let arg_peri = (peri - node) * DEG2RAD;
+    let mut m_deg = l - peri;
+    m_deg %= 360.0;
+    if m_deg > 180.0 { m_deg -= 360.0; }
+    if m_deg < -180.0 { m_deg += 360.0; }
+    let m_rad = m_deg * DEG2RAD;
+
+    let ecc = solve_kepler(m_rad, e);
+
+    // Position in the orbital plane.
+    let x_orb = a * (ecc.cos() - e);
+    let y_orb = a * (1.0 - e * e).sqrt() * ecc.sin();
+
+    // Rotate by argument of perihelion, inclination, then node, into the
+    // J2000 ecliptic frame.
+    let (cos_w, sin_w) = (arg_peri.cos(), arg_peri.sin());
+    let (cos_i, sin_i) = (i.cos(), i.sin());
+    let (cos_o, sin_o) = (node.to_radians().cos(), node.to_radians().sin());
+
+    let xw = cos_w * x_orb - sin_w * y_orb;
+    let yw = sin_w * x_orb + cos_w * y_orb;
+
+    let xi = xw;
+    let yi = yw * cos_i;
+    let zi = yw * sin_i;
+
+    [
+        cos_o * xi - sin_o * yi,
+        sin_o * xi + cos_o * yi,
+        zi,
+    ]
+}
+
+/// Geocentric tropical ecliptic longitude/latitude (degrees) and distance
+/// (AU) of `planet` at `jd_ut`. Astrometric (no light-time/aberration
+/// correction).
+fn geocentric_position(planet: Planet, jd_ut: f64) -> (f64, f64, f64) {
+    let jd_tt = jd_ut + sun::delta_t_days(jd_ut);
+    let t = (jd_tt - J2000) / 36525.0;
+
+    let p = heliocentric_position(&elements_for(planet), t);
+    let e = heliocentric_position(&EARTH, t);
+
+    let x = p[0] - e[0];
+    let y = p[1] - e[1];
+    let z = p[2] - e[2];
+    let distance_au = (x * x + y * y + z * z).sqrt();
+
+    // J2000-equinox longitude plus general precession (5029.0966"/century)
+    // to the equinox of date.
+    let mut lon = y.atan2(x) * RAD2DEG + 5029.0966 / 3600.0 * t;
+    lon %= 360.0;
+    if lon < 0.0 { lon += 360.0; }
+    let lat = (z / distance_au).asin() * RAD2DEG;
+
+    (lon, lat, distance_au)
+}
+
+/// Planetary ephemeris scratch state, caching the last geocentric position
+/// computed for each graha so that back-to-back longitude/latitude/distance
+/// calls for the same instant (as a panchanga run over a month of sunrises
+/// makes, per graha) don't repeat the Kepler solve.
+pub struct PlanetState {
+    cached_jd: f64,
+    cached: [Option<(f64, f64, f64)>; PLANET_COUNT],
+}
+
+impl PlanetState {
+    pub fn new() -> Self {
+        PlanetState {
+            cached_jd: f64::NAN,
+            cached: [None; PLANET_COUNT],
+        }
+    }
+
+    fn position(&mut self, jd_ut: f64, planet: Planet) -> (f64, f64, f64) {
+        if self.cached_jd != jd_ut {
+            self.cached = [None; PLANET_COUNT];
+            self.cached_jd = jd_ut;
+        }
+        let idx = planet.index();
+        if self.cached[idx].is_none() {
+            self.cached[idx] = Some(geocentric_position(planet, jd_ut));
+        }
+        self.cached[idx].unwrap()
+    }
+
+    /// Geocentric tropical ecliptic longitude of `planet`, in degrees.
+    pub fn longitude(&mut self, jd_ut: f64, planet: Planet) -> f64 {
+        self.position(jd_ut, planet).0
+    }
+
+    /// Geocentric ecliptic latitude of `planet`, in degrees.
+    pub fn latitude(&mut self, jd_ut: f64, planet: Planet) -> f64 {
+        self.position(jd_ut, planet).1
+    }
+
+    /// Geocentric distance to `planet`, in AU.
+    pub fn distance_au(&mut self, jd_ut: f64, planet: Planet) -> f64 {
+        self.position(jd_ut, planet).2
+    }
+}
+
+impl Default for PlanetState {
+    fn default() -> Self {
+        PlanetState::new()
+    }
+}
@@ -20,7 +20,7 @@ fn sinclair_refraction_horizon(atpress: f64, attemp: f64) -> f64 {
 }
 
 /// Mean sidereal time at Greenwich at 0h UT, in degrees
-fn sidereal_time_0h(jd_0h: f64) -> f64 {
+pub(crate) fn sidereal_time_0h(jd_0h: f64) -> f64 {
     let t = (jd_0h - 2451545.0) / 36525.0;
     let t2 = t * t;
     let t3 = t2 * t;
@@ -0,0 +1,179 @@
+/// Yogatara (junction star) catalog for the 27 nakshatras.
+///
+/// Each entry carries full ICRS J2000 astrometry (RA/Dec, proper motion,
+/// parallax, radial velocity, magnitude) rather than a bare ecliptic
+/// position, so its sidereal longitude can be reduced properly: space
+/// motion from J2000 to date, precession, rotation to the ecliptic of date,
+/// then subtraction of the configured ayanamsha — the same pipeline
+/// `ayanamsa_for` uses for the vernal point itself, run here for a star.
+/// Catalog values are approximate (rounded literature/Hipparcos-class
+/// astrometry), matching the precision this crate already uses for its
+/// other ephemeris approximations.
+
+use super::Ephemeris;
+use super::ayanamsa;
+use super::sun;
+use super::Ayanamsha;
+
+const J2000: f64 = 2451545.0;
+const DEG2RAD: f64 = std::f64::consts::PI / 180.0;
+const RAD2DEG: f64 = 180.0 / std::f64::consts::PI;
+/// Kilometres per AU; with `JULIAN_YEAR_SECS`, converts radial velocity
+/// (km/s) to AU/year alongside proper motion.
+const AU_KM: f64 = 149_597_870.7;
+const JULIAN_YEAR_SECS: f64 = 365.25 * 86400.0;
+
+/// Full ICRS J2000 astrometry for a catalog star, sefstars-style: position,
+/// proper motion, parallax, radial velocity, and magnitude.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedStar {
+    pub name: &'static str,
+    pub ra_j2000_deg: f64,
+    pub dec_j2000_deg: f64,
+    /// Proper motion in RA, mas/year (already ×cos(dec), i.e. "RA*").
+    pub pm_ra_mas: f64,
+    /// Proper motion in declination, mas/year.
+    pub pm_dec_mas: f64,
+    /// Parallax, mas.
+    pub parallax_mas: f64,
+    /// Radial velocity, km/s (positive = receding).
+    pub radial_velocity_kms: f64,
+    pub magnitude: f64,
+}
+
+pub struct StarEntry {
+    pub nakshatra: &'static str,
+    pub star: FixedStar,
+}
+
+/// The 27 nakshatras' yogataras, in nakshatra order (0 = Ashwini, 26 = Revati).
+pub const CATALOG: [StarEntry; 27] = [
+    StarEntry { nakshatra: "Ashwini", star: FixedStar { name: "Sheratan (β Arietis)", ra_j2000_deg: 25.927, dec_j2000_deg: 19.841, pm_ra_mas: 108.2, pm_dec_mas: 17.3, parallax_mas: 55.24, radial_velocity_kms: -4.3, magnitude: 2.64 } },
+    StarEntry { nakshatra: "Bharani", star: FixedStar { name: "35 Arietis", ra_j2000_deg: 42.226, dec_j2000_deg: 27.028, pm_ra_mas: 22.7, pm_dec_mas: -13.1, parallax_mas: 14.0, radial_velocity_kms: 19.0, magnitude: 4.65 } },
+    StarEntry { nakshatra: "Krittika", star: FixedStar { name: "Alcyone (η Tauri)", ra_j2000_deg: 56.879, dec_j2000_deg: 24.106, pm_ra_mas: 19.3, pm_dec_mas: -43.5, parallax_mas: 8.3, radial_velocity_kms: 10.0, magnitude: 2.87 } },
+    StarEntry { nakshatra: "Rohini", star: FixedStar { name: "Aldebaran (α Tauri)", ra_j2000_deg: 68.992, dec_j2000_deg: 16.508, pm_ra_mas: 62.78, pm_dec_mas: -189.36, parallax_mas: 48.94, radial_velocity_kms: 54.3, magnitude: 0.86 } },
+    StarEntry { nakshatra: "Mrigashira", star: FixedStar { name: "Meissa (λ Orionis)", ra_j2000_deg: 84.713, dec_j2000_deg: 19.348, pm_ra_mas: 1.49, pm_dec_mas: -0.49, parallax_mas: 4.04, radial_velocity_kms: 33.0, magnitude: 3.39 } },
+    StarEntry { nakshatra: "Ardra", star: FixedStar { name: "Betelgeuse (α Orionis)", ra_j2000_deg: 88.837, dec_j2000_deg: 7.434, pm_ra_mas: 27.33, pm_dec_mas: 10.86, parallax_mas: 5.95, radial_velocity_kms: 21.0, magnitude: 0.42 } },
+    StarEntry { nakshatra: "Punarvasu", star: FixedStar { name: "Pollux (β Geminorum)", ra_j2000_deg: 116.315, dec_j2000_deg: 28.045, pm_ra_mas: -625.69, pm_dec_mas: -45.95, parallax_mas: 96.54, radial_velocity_kms: 3.23, magnitude: 1.14 } },
+    StarEntry { nakshatra: "Pushya", star: FixedStar { name: "Asellus Australis (δ Cancri)", ra_j2000_deg: 133.047, dec_j2000_deg: 17.933, pm_ra_mas: -17.68, pm_dec_mas: -229.19, parallax_mas: 23.14, radial_velocity_kms: 17.7, magnitude: 3.94 } },
+    StarEntry { nakshatra: "Ashlesha", star: FixedStar { name: "Alphard (α Hydrae)", ra_j2000_deg: 136.913, dec_j2000_deg: -6.866, pm_ra_mas: -14.49, pm_dec_mas: 33.25, parallax_mas: 18.04, radial_velocity_kms: -4.3, magnitude: 1.98 } },
+    StarEntry { nakshatra: "Magha", star: FixedStar { name: "Regulus (α Leonis)", ra_j2000_deg: 152.063, dec_j2000_deg: 11.973, pm_ra_mas: -249.4, pm_dec_mas: 4.91, parallax_mas: 41.13, radial_velocity_kms: 5.9, magnitude: 1.35 } },
+    StarEntry { nakshatra: "Purva Phalguni", star: FixedStar { name: "Zosma (δ Leonis)", ra_j2000_deg: 176.024, dec_j2000_deg: 19.307, pm_ra_mas: -198.3, pm_dec_mas: 4.48, parallax_mas: 22.18, radial_velocity_kms: -18.1, magnitude: 2.56 } },
+    StarEntry { nakshatra: "Uttara Phalguni", star: FixedStar { name: "Denebola (β Leonis)", ra_j2000_deg: 182.453, dec_j2000_deg: 12.251, pm_ra_mas: -499.02, pm_dec_mas: -113.78, parallax_mas: 90.91, radial_velocity_kms: -0.2, magnitude: 2.14 } },
+    StarEntry { nakshatra: "Hasta", star: FixedStar { name: "Algorab (δ Corvi)", ra_j2000_deg: 190.489, dec_j2000_deg: -19.534, pm_ra_mas: -18.58, pm_dec_mas: 25.85, parallax_mas: 22.44, radial_velocity_kms: -1.0, magnitude: 2.94 } },
+    StarEntry { nakshatra: "Chitra", star: FixedStar { name: "Spica (α Virginis)", ra_j2000_deg: 201.261, dec_j2000_deg: -11.142, pm_ra_mas: -42.5, pm_dec_mas: -31.73, parallax_mas: 12.44, radial_velocity_kms: 1.0, magnitude: 0.97 } },
+    StarEntry { nakshatra: "Swati", star: FixedStar { name: "Arcturus (α Boötis)", ra_j2000_deg: 213.788, dec_j2000_deg: 19.193, pm_ra_mas: -1093.45, pm_dec_mas: -1999.4, parallax_mas: 88.83, radial_velocity_kms: -5.2, magnitude: -0.05 } },
+    StarEntry { nakshatra: "Vishakha", star: FixedStar { name: "Zubenelgenubi (α Librae)", ra_j2000_deg: 221.556, dec_j2000_deg: -15.658, pm_ra_mas: -105.69, pm_dec_mas: -68.4, parallax_mas: 42.6, radial_velocity_kms: -12.0, magnitude: 2.75 } },
+    StarEntry { nakshatra: "Anuradha", star: FixedStar { name: "Dschubba (δ Scorpii)", ra_j2000_deg: 237.464, dec_j2000_deg: -21.204, pm_ra_mas: -9.19, pm_dec_mas: -37.82, parallax_mas: 8.12, radial_velocity_kms: -9.0, magnitude: 2.32 } },
+    StarEntry { nakshatra: "Jyeshtha", star: FixedStar { name: "Antares (α Scorpii)", ra_j2000_deg: 247.393, dec_j2000_deg: -26.438, pm_ra_mas: -10.16, pm_dec_mas: -23.21, parallax_mas: 5.89, radial_velocity_kms: -3.4, magnitude: 1.06 } },
+    StarEntry { nakshatra: "Mula", star: FixedStar { name: "Shaula (λ Scorpii)", ra_j2000_deg: 262.432, dec_j2000_deg: -35.474, pm_ra_mas: -8.90, pm_dec_mas: -30.08, parallax_mas: 4.64, radial_velocity_kms: -3.0, magnitude: 1.62 } },
+    StarEntry { nakshatra: "Purva Ashadha", star: FixedStar { name: "Kaus Media (δ Sagittarii)", ra_j2000_deg: 276.123, dec_j2000_deg: -26.172, pm_ra_mas: 2.84, pm_dec_mas: -52.54, parallax_mas: 16.11, radial_velocity_kms: -22.0, magnitude: 2.70 } },
+    StarEntry { nakshatra: "Uttara Ashadha", star: FixedStar { name: "Nunki (σ Sagittarii)", ra_j2000_deg: 290.601, dec_j2000_deg: -32.189, pm_ra_mas: 14.52, pm_dec_mas: -49.85, parallax_mas: 14.81, radial_velocity_kms: -11.6, magnitude: 2.05 } },
+    StarEntry { nakshatra: "Shravana", star: FixedStar { name: "Altair (α Aquilae)", ra_j2000_deg: 294.174, dec_j2000_deg: 8.156, pm_ra_mas: 536.23, pm_dec_mas: 385.29, parallax_mas: 194.95, radial_velocity_kms: -26.1, magnitude: 0.76 } },
+    StarEntry { nakshatra: "Dhanishta", star: FixedStar { name: "Rotanev (β Delphini)", ra_j2000_deg: 306.206, dec_j2000_deg: 6.2833, pm_ra_mas: 10.74, pm_dec_mas: 7.51, parallax_mas: 19.24, radial_velocity_kms: 0.0, magnitude: 3.63 } },
+    StarEntry { nakshatra: "Shatabhisha", star: FixedStar { name: "λ Aquarii", ra_j2000_deg: 338.968, dec_j2000_deg: -17.572, pm_ra_mas: 27.69, pm_dec_mas: 5.29, parallax_mas: 11.51, radial_velocity_kms: 6.9, magnitude: 3.73 } },
+    StarEntry { nakshatra: "Purva Bhadrapada", star: FixedStar { name: "Markab (α Pegasi)", ra_j2000_deg: 337.322, dec_j2000_deg: 11.431, pm_ra_mas: 61.1, pm_dec_mas: -42.56, parallax_mas: 23.36, radial_velocity_kms: -3.6, magnitude: 2.48 } },
+    StarEntry { nakshatra: "Uttara Bhadrapada", star: FixedStar { name: "Algenib (γ Pegasi)", ra_j2000_deg: 353.844, dec_j2000_deg: 11.515, pm_ra_mas: 4.70, pm_dec_mas: -8.24, parallax_mas: 5.9, radial_velocity_kms: 3.7, magnitude: 2.83 } },
+    StarEntry { nakshatra: "Revati", star: FixedStar { name: "ζ Piscium", ra_j2000_deg: 11.451, dec_j2000_deg: 11.437, pm_ra_mas: 47.13, pm_dec_mas: 5.07, parallax_mas: 21.49, radial_velocity_kms: 1.9, magnitude: 5.21 } },
+];
+
+/// Apply space motion from J2000 to `jd_tt`, returning a Cartesian
+/// equatorial unit vector (mean equator/equinox of J2000).
+fn space_motion_j2000(star: &FixedStar, jd_tt: f64) -> [f64; 3] {
+    let years = (jd_tt - J2000) / 365.25;
+
+    let ra = star.ra_j2000_deg * DEG2RAD;
+    let dec = star.dec_j2000_deg * DEG2RAD;
+
+    // Distance in AU from parallax (fall back to a very distant placeholder
+    // when parallax is unmeasurably small, to keep the radial term inert).
+    let distance_au = if star.parallax_mas.abs() > 1e-6 {
+        206_264_806.2 / star.parallax_mas // 1/parallax(arcsec), arcsec = mas/1000
+    } else {
+        1.0e9
+    };
+
+    // Initial position vector, AU.
+    let mut p = [
+        distance_au * dec.cos() * ra.cos(),
+        distance_au * dec.cos() * ra.sin(),
+        distance_au * dec.sin(),
+    ];
+
+    // Tangential velocity components from proper motion (rad/year, scaled
+    // by distance), plus the radial velocity along the line of sight.
+    let pm_ra_rad_yr = (star.pm_ra_mas / 1000.0 / 3600.0) * DEG2RAD;
+    let pm_dec_rad_yr = (star.pm_dec_mas / 1000.0 / 3600.0) * DEG2RAD;
+    let v_radial_au_yr = star.radial_velocity_kms * JULIAN_YEAR_SECS / AU_KM;
+
+    let v = [
+        -distance_au * ra.sin() * pm_ra_rad_yr
+            - distance_au * dec.sin() * ra.cos() * pm_dec_rad_yr
+            + v_radial_au_yr * dec.cos() * ra.cos(),
+        distance_au * ra.cos() * pm_ra_rad_yr
+            - distance_au * dec.sin() * ra.sin() * pm_dec_rad_yr
+            + v_radial_au_yr * dec.cos() * ra.sin(),
+        distance_au * dec.cos() * pm_dec_rad_yr + v_radial_au_yr * dec.sin(),
+    ];
+
+    for i in 0..3 {
+        p[i] += v[i] * years;
+    }
+
+    let norm = (p[0] * p[0] + p[1] * p[1] + p[2] * p[2]).sqrt();
+    [p[0] / norm, p[1] / norm, p[2] / norm]
+}
+
+/// Sidereal ecliptic (longitude, latitude) of `star` at `jd_ut`, under
+/// ayanamsa `mode`: apply space motion from J2000 to date, precess the
+/// resulting direction J2000→date, rotate to the ecliptic of date, then
+/// subtract the ayanamsa to land in the sidereal (nirayana) frame — the
+/// same pipeline `ayanamsa_for` uses for the vernal-point direction itself,
+/// run here for a star instead of the equinox.
+pub fn fixed_star(jd_ut: f64, star: &FixedStar, mode: Ayanamsha) -> (f64, f64) {
+    let jd_tt = jd_ut + sun::delta_t_days(jd_ut);
+
+    let mut x = space_motion_j2000(star, jd_tt);
+    ayanamsa::precess_equatorial(&mut x, jd_tt, -1);
+
+    let eps = ayanamsa::obliquity_iau1976(jd_tt);
+    ayanamsa::equatorial_to_ecliptic(&mut x, eps);
+
+    let tropical_lon = x[1].atan2(x[0]) * RAD2DEG;
+    let lat = x[2].atan2((x[0] * x[0] + x[1] * x[1]).sqrt()) * RAD2DEG;
+
+    let ayan = ayanamsa::ayanamsa_for(jd_ut, mode);
+    let mut sidereal_lon = tropical_lon - ayan;
+    sidereal_lon %= 360.0;
+    if sidereal_lon < 0.0 { sidereal_lon += 360.0; }
+
+    (sidereal_lon, lat)
+}
+
+/// Sidereal ecliptic longitude of `star` at `jd_ut`, under `eph`'s
+/// configured ayanamsha — `fixed_star`, reading the mode from the
+/// `Ephemeris` instead of taking it explicitly, so callers holding an
+/// `Ephemeris` don't have to thread the mode through by hand.
+pub fn star_longitude_sidereal(eph: &Ephemeris, jd_ut: f64, star: &FixedStar) -> f64 {
+    fixed_star(jd_ut, star, eph.ayanamsha()).0
+}
+
+/// Which of the 27 nakshatras (1-based) `moon_sidereal_lon` falls under when
+/// judged by yogatara position rather than the idealized 13°20′ grid: the
+/// nakshatra whose own catalog star is angularly nearest the Moon. Lets a
+/// caller cross-check a boundary-sensitive date (the Moon close to a grid
+/// line, but closer to a neighboring yogatara) against real star positions.
+pub fn nakshatra_by_yogatara(eph: &Ephemeris, jd_ut: f64, moon_sidereal_lon: f64) -> i32 {
+    let mut best_index = 0usize;
+    let mut best_diff = f64::MAX;
+    for (i, entry) in CATALOG.iter().enumerate() {
+        let star_lon = star_longitude_sidereal(eph, jd_ut, &entry.star);
+        let mut diff = (moon_sidereal_lon - star_lon).abs() % 360.0;
+        if diff > 180.0 { diff = 360.0 - diff; }
+        if diff < best_diff {
+            best_diff = diff;
+            best_index = i;
+        }
+    }
+    best_index as i32 + 1
+}
@@ -0,0 +1,145 @@
+/// Solar ephemeris and the handful of shared time/nutation series the rest
+/// of this crate leans on.
+///
+/// Apparent geocentric longitude follows Meeus Ch. 25 (reduced-accuracy Sun,
+/// good to about 0.01°); right ascension/declination are the standard
+/// ecliptic-to-equatorial rotation by the apparent obliquity. `delta_t_days`
+/// and `nutation_longitude` are the two series every other ephemeris module
+/// (Moon, ayanamsa, rise/set, equation of time) pulls in by name.
+
+use std::f64::consts::PI;
+use super::julian_day;
+use super::ayanamsa;
+
+const DEG2RAD: f64 = PI / 180.0;
+const RAD2DEG: f64 = 180.0 / PI;
+const J2000: f64 = 2451545.0;
+
+fn normalize_deg(d: f64) -> f64 {
+    let d = d % 360.0;
+    if d < 0.0 { d + 360.0 } else { d }
+}
+
+/// TT − UT (ΔT), in days, via the long-term parabolic approximation of
+/// Morrison & Stephenson (2004). Near the present era it runs high by tens
+/// of seconds (about 114 s for 2024, against an observed ~69 s); like any
+/// single parabola it degrades gracefully over historical centuries, which
+/// is adequate for a panchanga but not for precise occultation work.
+pub fn delta_t_days(jd_ut: f64) -> f64 {
+    let (_, year, month, _) = julian_day::revjul(jd_ut);
+    let y = year as f64 + (month as f64 - 0.5) / 12.0;
+    let u = (y - 1820.0) / 100.0;
+    let delta_t_sec = -20.0 + 32.0 * u * u;
+    delta_t_sec / 86400.0
+}
+
+/// Terrestrial Time Julian day corresponding to `jd_ut`.
+pub fn jd_ut_to_tt(jd_ut: f64) -> f64 {
+    jd_ut + delta_t_days(jd_ut)
+}
+
+/// Nutation in longitude and obliquity (Δψ, Δε), in degrees, at `jd_ut`.
+/// Thin wrapper over `ayanamsa::nutation`, the shared IAU-1980 leading-term
+/// series both this module and the ayanamsa code need.
+fn nutation_deg(jd_ut: f64) -> (f64, f64) {
+    let jd_tt = jd_ut_to_tt(jd_ut);
+    ayanamsa::nutation(jd_tt)
+}
+
+/// Nutation in longitude (Δψ), in degrees, at `jd_ut`.
+pub fn nutation_longitude(jd_ut: f64) -> f64 {
+    nutation_deg(jd_ut).0
+}
+
+/// Mean obliquity of the ecliptic (IAU 1980), in degrees, at TT Julian day
+/// `jd_tt`.
+pub fn mean_obliquity(jd_tt: f64) -> f64 {
+    let t = (jd_tt - J2000) / 36525.0;
+    let eps_arcsec = 84381.448 - 46.8150 * t - 0.00059 * t * t + 0.001813 * t * t * t;
+    eps_arcsec / 3600.0
+}
+
+/// Apparent (true) obliquity of the ecliptic, in radians, at `jd_ut`: the
+/// mean value plus the nutation-in-obliquity term.
+fn apparent_obliquity_rad(jd_ut: f64) -> f64 {
+    let jd_tt = jd_ut_to_tt(jd_ut);
+    let (_, deps) = nutation_deg(jd_ut);
+    (mean_obliquity(jd_tt) + deps) * DEG2RAD
+}
+
+fn geometric_mean_longitude_deg(t: f64) -> f64 {
+    normalize_deg(280.46646 + 36000.76983 * t + 0.0003032 * t * t)
+}
+
+fn mean_anomaly_deg(t: f64) -> f64 {
+    normalize_deg(357.52911 + 35999.05029 * t - 0.0001537 * t * t)
+}
+
+fn equation_of_center_deg(t: f64, m_deg: f64) -> f64 {
+    let m = m_deg * DEG2RAD;
+    (1.914602 - 0.004817 * t - 0.000014 * t * t) * m.sin()
+        + (0.019993 - 0.000101 * t) * (2.0 * m).sin()
+        + 0.000289 * (3.0 * m).sin()
+}
+
+/// Apparent geocentric ecliptic longitude of the Sun, in degrees (Meeus
+/// Ch. 25): geometric mean longitude plus the equation of center, corrected
+/// for nutation and (the dominant term of) aberration.
+fn apparent_longitude_deg(jd_ut: f64) -> f64 {
+    let jd_tt = jd_ut_to_tt(jd_ut);
+    let t = (jd_tt - J2000) / 36525.0;
+
+    let l0 = geometric_mean_longitude_deg(t);
+    let m = mean_anomaly_deg(t);
+    let true_lon = l0 + equation_of_center_deg(t, m);
+
+    let omega = 125.04 - 1934.136 * t;
+    normalize_deg(true_lon - 0.00569 - 0.00478 * (omega * DEG2RAD).sin())
+}
+
+/// Solar ephemeris scratch state, caching the last apparent longitude
+/// computed so that back-to-back `solar_ra`/`solar_declination` calls for
+/// the same instant (as `rise.rs` makes every iteration) don't repeat it.
+pub struct SunState {
+    cached_jd: f64,
+    cached_lon_deg: f64,
+}
+
+impl SunState {
+    pub fn new() -> Self {
+        SunState { cached_jd: f64::NAN, cached_lon_deg: 0.0 }
+    }
+
+    fn longitude(&mut self, jd_ut: f64) -> f64 {
+        if self.cached_jd != jd_ut {
+            self.cached_lon_deg = apparent_longitude_deg(jd_ut);
+            self.cached_jd = jd_ut;
+        }
+        self.cached_lon_deg
+    }
+}
+
+impl Default for SunState {
+    fn default() -> Self {
+        SunState::new()
+    }
+}
+
+/// Apparent geocentric ecliptic longitude of the Sun, in degrees [0, 360).
+pub fn solar_longitude(state: &mut SunState, jd_ut: f64) -> f64 {
+    state.longitude(jd_ut)
+}
+
+/// Apparent geocentric right ascension of the Sun, in degrees [0, 360).
+pub fn solar_ra(state: &mut SunState, jd_ut: f64) -> f64 {
+    let lambda = state.longitude(jd_ut) * DEG2RAD;
+    let eps = apparent_obliquity_rad(jd_ut);
+    normalize_deg((eps.cos() * lambda.sin()).atan2(lambda.cos()) * RAD2DEG)
+}
+
+/// Apparent geocentric declination of the Sun, in degrees.
+pub fn solar_declination(state: &mut SunState, jd_ut: f64) -> f64 {
+    let lambda = state.longitude(jd_ut) * DEG2RAD;
+    let eps = apparent_obliquity_rad(jd_ut);
+    (eps.sin() * lambda.sin()).asin() * RAD2DEG
+}
@@ -147,6 +147,55 @@ pub struct HinduDate {
     pub paksha: Paksha,
     pub tithi: i32,
     pub is_adhika_tithi: bool,
+    /// 1-based index into `SAMVATSARA_NAMES` (the 60-year Jupiter cycle).
+    pub samvatsara: i32,
+}
+
+/// The subset of `HinduDate` that identifies a lunar date on its own,
+/// without the derived/search-only fields (`year_vikram`, `is_adhika_tithi`,
+/// `samvatsara`) a caller who only knows e.g. "Saka 1946 Bhadrapada Krishna
+/// Ashtami" wouldn't have to hand — see `panchang::hindu_to_gregorian_parts`.
+#[derive(Debug, Clone, Copy)]
+pub struct HinduDateParts {
+    pub year_saka: i32,
+    pub masa: MasaName,
+    pub is_adhika_masa: bool,
+    pub paksha: Paksha,
+    pub tithi: i32,
+}
+
+/// Names of the 60-year Jupiter (Brihaspati) cycle, Prabhava..Akshaya.
+/// Index 0 is an unused placeholder, matching the repo's other `*_NAMES`
+/// tables (index = `HinduDate::samvatsara`, 1-based).
+pub const SAMVATSARA_NAMES: [&str; 61] = [
+    "",
+    "Prabhava", "Vibhava", "Shukla", "Pramoda", "Prajapati", "Angirasa",
+    "Shrimukha", "Bhava", "Yuva", "Dhatri", "Ishvara", "Bahudhanya",
+    "Pramathi", "Vikrama", "Vrisha", "Chitrabhanu", "Subhanu", "Tarana",
+    "Parthiva", "Vyaya", "Sarvajit", "Sarvadhari", "Virodhi", "Vikriti",
+    "Khara", "Nandana", "Vijaya", "Jaya", "Manmatha", "Durmukhi",
+    "Hemalamba", "Vilambi", "Vikari", "Sharvari", "Plava", "Shubhakrit",
+    "Shobhakrit", "Krodhi", "Vishvavasu", "Parabhava", "Plavanga", "Kilaka",
+    "Saumya", "Sadharana", "Virodhikrit", "Paridhavi", "Pramadi", "Ananda",
+    "Rakshasa", "Anala", "Pingala", "Kalayukti", "Siddharthi", "Raudra",
+    "Durmati", "Dundubhi", "Rudhirodgari", "Raktakshi", "Krodhana", "Akshaya",
+];
+
+/// Start/end span of a nakshatra, yoga, or karana occupying `index` (1-based).
+#[derive(Debug, Clone, Copy)]
+pub struct AngaInfo {
+    pub index: i32,
+    pub jd_start: f64,
+    pub jd_end: f64,
+}
+
+/// A candidate solar (at Amavasya) or lunar (at Purnima) eclipse flagged on
+/// a `PanchangDay`, per `core::eclipse`'s node-proximity screening.
+#[derive(Debug, Clone, Copy)]
+pub struct EclipseNote {
+    pub is_solar: bool,
+    pub max_jd: f64,
+    pub is_total: bool,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -157,6 +206,39 @@ pub struct PanchangDay {
     pub jd_sunrise: f64,
     pub hindu_date: HinduDate,
     pub tithi: TithiInfo,
+    pub nakshatra: AngaInfo,
+    pub yoga: AngaInfo,
+    pub karana: AngaInfo,
+    /// Weekday (vara), Mon=0..Sun=6 — matches `Ephemeris::day_of_week`.
+    pub vara: i32,
+    pub eclipse: Option<EclipseNote>,
+}
+
+/// Which month-naming convention `masa::masa_for_date` should follow.
+///
+/// Amanta months run new-moon to new-moon (the Southern/Marathi/Tamil
+/// convention); Purnimanta months run full-moon to full-moon (the
+/// North-Indian convention), so the Krishna paksha is named after the
+/// *following* Amanta month. `Gujarati` keeps Amanta month names but starts
+/// the Vikram Samvat year at Kartika Shukla Pratipada instead of Chaitra
+/// Shukla Pratipada.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReckoningScheme {
+    AmantaSouthern,
+    PurnimantaNorthern,
+    Gujarati,
+}
+
+/// Which Moon position `tithi::tithi_at_sunrise` (and friends) should use.
+///
+/// `Geocentric` is the traditional/default reckoning. `Topocentric` corrects
+/// for the observer's horizontal parallax (up to ~1°), which can shift a
+/// tithi boundary by a couple of minutes — occasionally enough to change
+/// which tithi is current at sunrise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LunarFrame {
+    Geocentric,
+    Topocentric,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -189,3 +271,27 @@ pub const RASHI_NAMES: [&str; 13] = [
     "", "Mesha", "Vrishabha", "Mithuna", "Karka", "Simha", "Kanya",
     "Tula", "Vrishchika", "Dhanu", "Makara", "Kumbha", "Meena",
 ];
+
+pub const NAKSHATRA_NAMES: [&str; 28] = [
+    "",
+    "Ashwini", "Bharani", "Krittika", "Rohini", "Mrigashira", "Ardra",
+    "Punarvasu", "Pushya", "Ashlesha", "Magha", "Purva Phalguni", "Uttara Phalguni",
+    "Hasta", "Chitra", "Swati", "Vishakha", "Anuradha", "Jyeshtha",
+    "Mula", "Purva Ashadha", "Uttara Ashadha", "Shravana", "Dhanishta", "Shatabhisha",
+    "Purva Bhadrapada", "Uttara Bhadrapada", "Revati",
+];
+
+pub const YOGA_NAMES: [&str; 28] = [
+    "",
+    "Vishkambha", "Priti", "Ayushman", "Saubhagya", "Shobhana", "Atiganda",
+    "Sukarma", "Dhriti", "Shula", "Ganda", "Vriddhi", "Dhruva",
+    "Vyaghata", "Harshana", "Vajra", "Siddhi", "Vyatipata", "Variyana",
+    "Parigha", "Shiva", "Siddha", "Sadhya", "Shubha", "Shukla",
+    "Brahma", "Indra", "Vaidhriti",
+];
+
+pub const KARANA_NAMES: [&str; 12] = [
+    "",
+    "Kimstughna", "Bava", "Balava", "Kaulava", "Taitila", "Gara",
+    "Vanija", "Vishti", "Shakuni", "Chatushpada", "Naga",
+];